@@ -0,0 +1,39 @@
+//! Records the commit the binary is built from, for keying timings by it.
+
+use std::{path::Path, process::Command};
+
+fn git(args: &[&str]) -> Option<String> {
+    Command::new("git")
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn main() {
+    let commit = match git(&["rev-parse", "--short=12", "HEAD"]) {
+        Some(commit) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(changes) if changes.is_empty() => commit,
+            _ => format!("{commit}-dirty"),
+        },
+        None => "unknown".to_string(),
+    };
+    println!("cargo::rustc-env=AOC_COMMIT={commit}");
+
+    // Commits and checkouts move `HEAD` or the branch it is on, and edits touch the sources. Paths
+    // that don't exist would make the script run on every build, so they are only watched in
+    // checkouts.
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-changed=src");
+    println!("cargo::rerun-if-changed=Cargo.toml");
+    if let Some(git_dir) = git(&["rev-parse", "--git-dir"]) {
+        let branch = git(&["rev-parse", "--symbolic-full-name", "HEAD"]);
+        for file in ["HEAD", "index"].into_iter().chain(branch.as_deref()) {
+            let path = Path::new(&git_dir).join(file);
+            if path.exists() {
+                println!("cargo::rerun-if-changed={}", path.display());
+            }
+        }
+    }
+}
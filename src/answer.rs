@@ -0,0 +1,190 @@
+use std::{borrow::Cow, convert::Infallible, fmt::Display, str::FromStr};
+
+/// The answer to a puzzle.
+///
+/// Answers compare equal if their values do, so `Unsigned(3)`, `Signed(3)` and `Big(3)` are all
+/// the same answer. The metadata is only shown, and is ignored when comparing.
+#[derive(Debug, Clone)]
+pub struct Answer {
+    pub value: Value,
+    /// What the answer counts, like `presses`.
+    pub unit: Option<Cow<'static, str>>,
+    /// The drawing the answer is read off, for puzzles whose answer is a picture.
+    pub picture: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Value {
+    Unsigned(u64),
+    Signed(i64),
+    /// A number that does not fit into 64 bits.
+    Big(i128),
+    Text(String),
+}
+
+impl Value {
+    fn integer(&self) -> Option<i128> {
+        match *self {
+            Value::Unsigned(value) => Some(value.into()),
+            Value::Signed(value) => Some(value.into()),
+            Value::Big(value) => Some(value),
+            Value::Text(_) => None,
+        }
+    }
+}
+
+impl Answer {
+    pub fn new(value: Value) -> Self {
+        Self {
+            value,
+            unit: None,
+            picture: None,
+        }
+    }
+
+    pub fn with_unit(self, unit: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            unit: Some(unit.into()),
+            ..self
+        }
+    }
+
+    pub fn with_picture(self, picture: impl Into<String>) -> Self {
+        Self {
+            picture: Some(picture.into()),
+            ..self
+        }
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Text(a), Value::Text(b)) => a == b,
+            _ => self.integer().is_some_and(|a| Some(a) == other.integer()),
+        }
+    }
+}
+
+impl Eq for Value {}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for Answer {}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Unsigned(value) => write!(f, "{value}"),
+            Value::Signed(value) => write!(f, "{value}"),
+            Value::Big(value) => write!(f, "{value}"),
+            Value::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Shows the value only, as it would be submitted.
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+/// Reads an answer like the ones in answer files: numbers become numbers, anything else text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = if let Ok(value) = s.parse() {
+            Value::Unsigned(value)
+        } else if let Ok(value) = s.parse() {
+            Value::Signed(value)
+        } else if let Ok(value) = s.parse() {
+            Value::Big(value)
+        } else {
+            Value::Text(s.to_string())
+        };
+
+        Ok(Answer::new(value))
+    }
+}
+
+macro_rules! from_integer {
+    ($variant:ident, $($integer:ty),+) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Answer::new(Value::$variant(value as _))
+                }
+            }
+        )+
+    };
+}
+
+from_integer!(Unsigned, u8, u16, u32, u64, usize);
+from_integer!(Signed, i8, i16, i32, i64, isize);
+from_integer!(Big, i128);
+
+impl From<u128> for Answer {
+    /// # Panics
+    ///
+    /// If the value does not fit into an `i128`.
+    fn from(value: u128) -> Self {
+        Answer::new(Value::Big(value.try_into().expect("answer is too big")))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::new(Value::Text(value))
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::new(Value::Text(value.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answer, Value};
+
+    #[test]
+    fn equality() {
+        assert_eq!(Answer::from(3_usize), Answer::from(3_i32));
+        assert_eq!(Answer::from(3_i128), Answer::from(3_u8));
+        assert_ne!(Answer::from(3_usize), Answer::from(-3_i64));
+        assert_ne!(Answer::from(3_usize), Answer::from("3"));
+        assert_eq!(
+            Answer::from("EFEYKFRFIJ").with_picture("#### ..."),
+            Answer::from("EFEYKFRFIJ")
+        );
+        assert_eq!(
+            Answer::from(12_u64).with_unit("presses"),
+            Answer::from(12_u64)
+        );
+    }
+
+    #[test]
+    fn parse_and_display() {
+        for answer in [
+            "0",
+            "-17",
+            "170141183460469231731687303715884105727",
+            "ABC",
+            "1,2,3",
+        ] {
+            assert_eq!(answer.parse::<Answer>().unwrap().to_string(), answer);
+        }
+        assert_eq!("-17".parse::<Answer>().unwrap().value, Value::Signed(-17));
+        assert!(matches!(
+            "18446744073709551616".parse::<Answer>().unwrap().value,
+            Value::Big(_)
+        ));
+    }
+}
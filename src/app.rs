@@ -0,0 +1,135 @@
+use std::{
+    io::{Write, stdin, stdout},
+    process::ExitCode,
+};
+
+use crate::{
+    bisect,
+    cache::Cache,
+    check, cli, fetch,
+    history::{self, Build, History, Measurement},
+    log, output, repl, runner, scaffold, submit, watch,
+};
+
+/// Runs the command given on the command line.
+pub fn run() -> ExitCode {
+    let args = match cli::parse_command(std::env::args().skip(1)) {
+        Ok(cli::Command::Run(args)) => args,
+        Ok(cli::Command::Submit(args)) => return exit_code(submit::run(&args, stdout().lock())),
+        Ok(cli::Command::Fetch(args)) => return exit_code(fetch::run(&args, stdout().lock())),
+        Ok(cli::Command::NewDay(args)) => return exit_code(scaffold::run(&args, stdout().lock())),
+        Ok(cli::Command::Bisect(args)) => return exit_code(bisect::run(&args, stdout().lock())),
+        Ok(cli::Command::Repl(args)) => {
+            return exit_code(repl::run(&args, stdin().lock(), stdout().lock()));
+        }
+        Err(err) => {
+            eprintln!("error: {err}\n\n{}", cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    if args.list {
+        for day in args.event.days {
+            let parts: Vec<_> = day.parts().map(|part| part.to_string()).collect();
+            writeln!(
+                stdout().lock(),
+                "Day {}: `{}` ({})",
+                day.number,
+                day.module,
+                parts.join(", ")
+            )
+            .unwrap();
+        }
+
+        return ExitCode::SUCCESS;
+    }
+
+    if args.watch {
+        // The puzzles are run by a child process with the same arguments, which must not watch too.
+        let child_args: Vec<_> = std::env::args()
+            .skip(1)
+            .filter(|arg| arg != "--watch")
+            .collect();
+        if let Err(err) = watch::watch(&args, &child_args, stdout().lock()) {
+            eprintln!("error: {err}");
+        }
+
+        return ExitCode::FAILURE;
+    }
+
+    let answers = if args.check {
+        match check::Answers::load(&args.answers) {
+            Ok(answers) => Some(answers),
+            Err(err) => {
+                eprintln!("error: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        None
+    };
+
+    let mut history = match History::load(&args.history) {
+        Ok(history) => history,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let build = Build::current();
+    let measurement = Measurement::of(&args);
+    let mut cache = match Cache::load(&args.cache) {
+        Ok(cache) => cache,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut printer = output::Printer::new(args.format, stdout().lock());
+    let mut failed = false;
+    let mut timings = Vec::new();
+    runner::run(&args, answers.as_ref(), Some(&mut cache), |mut outcome| {
+        if let Some(elapsed) = history::timing(&outcome) {
+            if let Some(threshold) = args.compare {
+                outcome.regression = history.compare(
+                    &build,
+                    &measurement,
+                    outcome.day.number,
+                    outcome.part,
+                    elapsed,
+                    threshold,
+                );
+            }
+            timings.push((outcome.day.number, outcome.part, elapsed));
+        }
+        failed |= outcome.failed();
+        log::flush();
+        printer.print(&outcome);
+    });
+    let _ = printer.finish();
+    if let Err(err) = history.record(&build, &measurement, &timings) {
+        eprintln!("error: {err}");
+    }
+    if let Err(err) = cache.save() {
+        eprintln!("error: {err}");
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// The exit code of a command that reports whether it succeeded.
+fn exit_code(result: Result<bool, String>) -> ExitCode {
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Untimed runs before measuring, to warm up caches and the branch predictor.
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 20,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+    pub iterations: usize,
+}
+
+impl Stats {
+    /// Computes the statistics of a non-empty set of samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "need at least one sample");
+        samples.sort_unstable();
+
+        Self {
+            min: samples[0],
+            median: percentile(&samples, 50),
+            p95: percentile(&samples, 95),
+            max: samples[samples.len() - 1],
+            iterations: samples.len(),
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:.2?}, median {:.2?}, p95 {:.2?}, max {:.2?} ({} runs)",
+            self.min, self.median, self.p95, self.max, self.iterations
+        )
+    }
+}
+
+/// Nearest-rank percentile of sorted samples.
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    let rank = (p * sorted.len()).div_ceil(100).max(1);
+
+    sorted[rank - 1]
+}
+
+pub fn measure<T>(config: Config, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        let _ = std::hint::black_box(f());
+    }
+    let mut samples = Vec::with_capacity(config.iterations);
+    for _ in 0..config.iterations.max(1) {
+        let start = Instant::now();
+        let _ = std::hint::black_box(f());
+        samples.push(start.elapsed());
+    }
+
+    Stats::from_samples(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Stats;
+
+    #[test]
+    fn stats() {
+        let samples = (1..=100).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(50));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+        assert_eq!(stats.max, Duration::from_millis(100));
+        assert_eq!(stats.iterations, 100);
+    }
+
+    #[test]
+    fn single_sample() {
+        let stats = Stats::from_samples(vec![Duration::from_millis(7)]);
+
+        assert_eq!(stats.min, stats.max);
+        assert_eq!(stats.median, Duration::from_millis(7));
+        assert_eq!(stats.p95, Duration::from_millis(7));
+    }
+}
@@ -0,0 +1,234 @@
+use std::{fmt::Display, io::Write};
+
+use crate::{
+    answer::Answer,
+    cli::BisectArgs,
+    log,
+    panic::{self, Panic},
+    registry::{Day, Part},
+};
+
+/// How solving a puzzle failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Failure {
+    Panic(Panic),
+    Error(String),
+}
+
+impl Failure {
+    /// Whether this is the same failure as `other`: a panic at the same place, or any error.
+    fn reproduces(&self, other: &Failure) -> bool {
+        match (self, other) {
+            (Failure::Panic(a), Failure::Panic(b)) => a.location == b.location,
+            (Failure::Error(_), Failure::Error(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Panic(panic) => write!(f, "{panic}"),
+            Failure::Error(err) => write!(f, "failed: {err}"),
+        }
+    }
+}
+
+fn solve(day: &Day, part: Part, input: &str) -> Result<Answer, Failure> {
+    match panic::catch(|| {
+        day.puzzle
+            .parse(input)
+            .and_then(|parsed| parsed.solve(part))
+    }) {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(err)) => Err(Failure::Error(err.message)),
+        Err(panic) => Err(Failure::Panic(panic)),
+    }
+}
+
+/// Narrows down the input of a puzzle that fails to the lines that make it fail, and writes
+/// them to `args.output`.
+pub fn run(args: &BisectArgs, mut out: impl Write) -> Result<bool, String> {
+    let (day, part) = (args.day, args.part);
+    if !day.has_part(part) {
+        return Err(format!("day {} has no puzzle `{part}`", day.number));
+    }
+    let input = args
+        .inputs
+        .read(day.number)
+        .map_err(|err| err.to_string())?;
+    let input = day.puzzle.normalize(&input);
+    let failure = match solve(day, part, &input) {
+        Ok(answer) => {
+            return Err(format!(
+                "day {}{part} solves its input (`{answer}`), so there is nothing to bisect",
+                day.number
+            ));
+        }
+        Err(failure) => failure,
+    };
+    writeln!(out, "Day {}{part} {failure}", day.number).unwrap();
+
+    let lines: Vec<_> = input.lines().collect();
+    let target = args.event.path(day);
+    let mut runs = 0;
+    let reproducer = minimize(&lines, |subset| {
+        runs += 1;
+        let reproduces = solve(day, part, &join(subset))
+            .err()
+            .is_some_and(|other| other.reproduces(&failure));
+        log::debug!(target: target, "{} lines reproduce it: {reproduces}", subset.len());
+        reproduces
+    });
+    let reproducer = join(&reproducer);
+    if let Err(failure) = solve(day, part, &reproducer) {
+        writeln!(out, "Reproduced with: {failure}").unwrap();
+    }
+    std::fs::write(&args.output, &reproducer)
+        .map_err(|err| format!("failed to write `{}`: {err}", args.output.display()))?;
+    writeln!(
+        out,
+        "Narrowed {} lines down to {} in {runs} runs, and wrote them to `{}`",
+        lines.len(),
+        reproducer.lines().count(),
+        args.output.display()
+    )
+    .unwrap();
+
+    Ok(true)
+}
+
+fn join(lines: &[&str]) -> String {
+    lines.iter().map(|line| format!("{line}\n")).collect()
+}
+
+/// Finds a small subset of `items` that still `reproduces` something that all of them do, keeping
+/// their order.
+///
+/// This is delta debugging: the items are split into chunks, and the search continues with the
+/// first chunk, or all but one chunk, that reproduces it. If none does, the chunks are halved. The
+/// result is minimal in that leaving out any single item of it no longer reproduces it.
+pub fn minimize<T: Clone>(items: &[T], mut reproduces: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut items = items.to_vec();
+    let mut chunks = 2;
+    while items.len() >= 2 {
+        let size = items.len().div_ceil(chunks);
+        let ranges: Vec<_> = (0..items.len())
+            .step_by(size)
+            .map(|start| start..(start + size).min(items.len()))
+            .collect();
+        if let Some(range) = ranges
+            .iter()
+            .find(|range| reproduces(&items[(*range).clone()]))
+        {
+            items = items[range.clone()].to_vec();
+            chunks = 2;
+            continue;
+        }
+        // With two chunks, the complements are the chunks themselves.
+        if ranges.len() > 2 {
+            let complement = ranges
+                .iter()
+                .map(|range| [&items[..range.start], &items[range.end..]].concat())
+                .find(|complement| reproduces(complement));
+            if let Some(complement) = complement {
+                items = complement;
+                chunks = (ranges.len() - 1).max(2);
+                continue;
+            }
+        }
+        if ranges.len() >= items.len() {
+            break;
+        }
+        chunks = (chunks * 2).min(items.len());
+    }
+
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{minimize, run};
+    use crate::{
+        answer::Answer,
+        cli::BisectArgs,
+        error::Error,
+        input::Inputs,
+        registry::{self, Day, Part},
+        solution::{Example, Solution},
+    };
+
+    #[test]
+    fn minimal_subsets() {
+        let items: Vec<_> = (0..100).collect();
+        let mut runs = 0;
+        let subset = minimize(&items, |subset| {
+            runs += 1;
+            subset.contains(&37) && subset.contains(&81)
+        });
+        assert_eq!(subset, [37, 81]);
+        assert!(runs < items.len(), "took {runs} runs");
+
+        assert_eq!(minimize(&items, |subset| subset.len() >= 3).len(), 3);
+        assert_eq!(minimize(&[1], |_| true), [1]);
+    }
+
+    /// A solver that panics if its input has both a 3 and a 7 in it.
+    #[derive(Clone)]
+    struct Picky;
+
+    impl Solution for Picky {
+        type Input<'a> = Vec<&'a str>;
+
+        const EXAMPLES: &'static [Example] = &[];
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+            Ok(input.lines().collect())
+        }
+
+        fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+            assert!(!input.contains(&"3") || !input.contains(&"7"));
+            Ok(input.len().into())
+        }
+
+        fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+            self.part_a(input)
+        }
+    }
+
+    static PICKY: Day = Day {
+        number: 1,
+        module: "picky",
+        puzzle: &Picky,
+    };
+
+    #[test]
+    fn reproducer() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-bisect-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("input.txt");
+        let lines: Vec<_> = (0..20).map(|n| n.to_string()).collect();
+        std::fs::write(&input, lines.join("\n")).unwrap();
+        let mut inputs = Inputs::default();
+        inputs.set_override(1, input.to_str().unwrap().into());
+        let args = BisectArgs {
+            event: registry::default_event(),
+            day: &PICKY,
+            part: Part::A,
+            inputs,
+            output: dir.join("reproducer.txt"),
+        };
+
+        let mut out = Vec::new();
+        let result = run(&args, &mut out);
+        let reproducer = std::fs::read_to_string(&args.output);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(result, Ok(true));
+        assert_eq!(reproducer.unwrap(), "3\n7\n");
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("Day 1a panicked at src/bisect.rs:"));
+        assert!(out.contains("Narrowed 20 lines down to 2 in "));
+    }
+}
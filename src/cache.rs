@@ -0,0 +1,226 @@
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::{answer::Answer, cli, log, registry::Part, sha256};
+
+/// Answers to puzzles on their inputs, with one `<day><part> <version> <checksum> <unit> <answer>`
+/// entry per line. The unit is `-` for answers without one.
+///
+/// An answer is only reused while the SHA-256 checksum of the input and the
+/// [version](crate::solution::Solution::VERSION) of the solution are the ones it was found with.
+#[derive(Debug)]
+pub struct Cache {
+    path: PathBuf,
+    entries: BTreeMap<(u8, Part), Entry>,
+    /// Whether entries were added or replaced since the cache was loaded.
+    changed: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    version: u32,
+    checksum: String,
+    answer: Answer,
+}
+
+impl PartialEq for Entry {
+    /// Unlike answers, entries also differ by unit and by how the answer is written.
+    fn eq(&self, other: &Self) -> bool {
+        self.version == other.version
+            && self.checksum == other.checksum
+            && self.answer.unit == other.answer.unit
+            && self.answer.to_string() == other.answer.to_string()
+    }
+}
+
+/// The checksum an input is cached under.
+pub fn checksum(input: &str) -> String {
+    sha256::hex_digest(input.as_bytes())
+}
+
+impl Cache {
+    /// Reads the cache at `path`. A missing file is treated as an empty one.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(format!("failed to read `{}`: {err}", path.display())),
+        };
+        let mut entries = BTreeMap::new();
+        for (idx, line) in content.lines().enumerate() {
+            let invalid = || format!("{}:{}: invalid entry `{line}`", path.display(), idx + 1);
+            let mut fields = line.splitn(5, ' ');
+            let (Some(puzzle), Some(version), Some(checksum), Some(unit), Some(answer)) = (
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+            ) else {
+                return Err(invalid());
+            };
+            let (day, part) = cli::parse_day_part(puzzle).ok_or_else(invalid)?;
+            if answer.is_empty() {
+                return Err(invalid());
+            }
+            let Ok(mut answer) = answer.parse::<Answer>();
+            if unit != "-" {
+                answer = answer.with_unit(unit.to_string());
+            }
+            let entry = Entry {
+                version: version.parse().map_err(|_| invalid())?,
+                checksum: checksum.to_string(),
+                answer,
+            };
+            let _ = entries.insert((day, part), entry);
+        }
+        log::debug!("loaded {} answers from `{}`", entries.len(), path.display());
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            entries,
+            changed: false,
+        })
+    }
+
+    /// The answer to a puzzle, if it was found by the same version of the solution on an input
+    /// with the same checksum.
+    pub fn get(&self, day: u8, part: Part, version: u32, checksum: &str) -> Option<Answer> {
+        let entry = self.entries.get(&(day, part))?;
+        if entry.version != version || entry.checksum != checksum {
+            log::debug!("the answer to {day}{part} is stale");
+            return None;
+        }
+
+        Some(entry.answer.clone())
+    }
+
+    /// Caches the answer to a puzzle, replacing the previous one. Answers that don't fit into an
+    /// entry, because they have a picture or span more than one line, or their unit is more than
+    /// one word, are not cached.
+    pub fn insert(&mut self, day: u8, part: Part, version: u32, checksum: &str, answer: &Answer) {
+        if answer.picture.is_some()
+            || answer.to_string().contains('\n')
+            || answer
+                .unit
+                .as_ref()
+                .is_some_and(|unit| unit.is_empty() || unit.contains(char::is_whitespace))
+        {
+            log::debug!("not caching the answer to {day}{part}, as it doesn't fit into an entry");
+            return;
+        }
+        let entry = Entry {
+            version,
+            checksum: checksum.to_string(),
+            answer: answer.clone(),
+        };
+        if self.entries.get(&(day, part)) != Some(&entry) {
+            let _ = self.entries.insert((day, part), entry);
+            self.changed = true;
+        }
+    }
+
+    /// Writes the cache back, if anything changed.
+    pub fn save(&self) -> Result<(), String> {
+        if !self.changed {
+            return Ok(());
+        }
+        let content: String = self
+            .entries
+            .iter()
+            .map(|((day, part), entry)| {
+                format!(
+                    "{day}{part} {} {} {} {}\n",
+                    entry.version,
+                    entry.checksum,
+                    entry.answer.unit.as_deref().unwrap_or("-"),
+                    entry.answer
+                )
+            })
+            .collect();
+
+        std::fs::write(&self.path, content)
+            .map_err(|err| format!("failed to write `{}`: {err}", self.path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cache, checksum};
+    use crate::{answer::Answer, registry::Part};
+
+    #[test]
+    fn reuse_and_invalidate() {
+        let path = std::env::temp_dir().join(format!("aoc2025-cache-{}.txt", std::process::id()));
+        let input = checksum("L68\nL30\n");
+        std::fs::write(
+            &path,
+            format!("1a 1 {input} - 3\n7b 2 {input} - two words\n10b 1 {input} presses 33\n"),
+        )
+        .unwrap();
+
+        let mut cache = Cache::load(&path).unwrap();
+        assert_eq!(cache.get(1, Part::A, 1, &input), Some(3_u64.into()));
+        assert_eq!(cache.get(1, Part::A, 2, &input), None);
+        assert_eq!(cache.get(1, Part::A, 1, &checksum("L68\n")), None);
+        assert_eq!(cache.get(1, Part::B, 1, &input), None);
+        assert_eq!(
+            cache.get(7, Part::B, 2, &input),
+            Some(Answer::from("two words"))
+        );
+        let unit = cache.get(10, Part::B, 1, &input).unwrap().unit;
+        assert_eq!(unit.as_deref(), Some("presses"));
+
+        cache.insert(1, Part::A, 2, &input, &4_u64.into());
+        cache.insert(1, Part::B, 1, &input, &Answer::from("#\n#"));
+        cache.insert(
+            7,
+            Part::A,
+            1,
+            &input,
+            &Answer::from("AB").with_picture("#.\n.#"),
+        );
+        cache.insert(10, Part::A, 1, &input, &7_u64.into());
+        cache.insert(
+            10,
+            Part::B,
+            1,
+            &input,
+            &Answer::from(33_u64).with_unit("light presses"),
+        );
+        cache.save().unwrap();
+        let reloaded = Cache::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(reloaded.get(1, Part::A, 2, &input), Some(4_u64.into()));
+        assert_eq!(reloaded.get(1, Part::B, 1, &input), None);
+        assert_eq!(reloaded.get(7, Part::A, 1, &input), None);
+        assert_eq!(reloaded.get(10, Part::A, 1, &input).unwrap().unit, None);
+        let unit = reloaded.get(10, Part::B, 1, &input).unwrap().unit;
+        assert_eq!(unit.as_deref(), Some("presses"));
+        assert_eq!(
+            reloaded.get(7, Part::B, 2, &input),
+            Some(Answer::from("two words"))
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let path =
+            std::env::temp_dir().join(format!("aoc2025-bad-cache-{}.txt", std::process::id()));
+        for (content, err) in [
+            (
+                "1a 1 abc - 3\n1a x abc - 3\n",
+                ":2: invalid entry `1a x abc - 3`",
+            ),
+            ("1a 1 abc 3\n", ":1: invalid entry `1a 1 abc 3`"),
+            ("1a 1 abc - \n", ":1: invalid entry `1a 1 abc - `"),
+        ] {
+            std::fs::write(&path, content).unwrap();
+            assert!(Cache::load(&path).unwrap_err().ends_with(err), "{content}");
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+}
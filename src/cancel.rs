@@ -0,0 +1,69 @@
+use std::{
+    cell::RefCell,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+/// A flag telling a solver that its result is no longer needed, for example because it took
+/// longer than `--timeout`.
+///
+/// Solvers can't be stopped from the outside, so long-running ones should check [`is_cancelled`]
+/// now and then, and return early with any answer once it is set.
+#[derive(Debug, Clone, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+impl Token {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `token` as the cancellation token of the current thread.
+pub fn with<T>(token: Token, f: impl FnOnce() -> T) -> T {
+    let previous = CURRENT.replace(Some(token));
+    let result = f();
+    CURRENT.set(previous);
+
+    result
+}
+
+/// The cancellation token of the current thread, to pass on to threads spawned by a solver.
+pub fn current() -> Token {
+    CURRENT.with_borrow(|token| token.clone().unwrap_or_default())
+}
+
+/// Whether the solver running on the current thread was cancelled.
+pub fn is_cancelled() -> bool {
+    CURRENT.with_borrow(|token| token.as_ref().is_some_and(Token::is_cancelled))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Token, current, is_cancelled, with};
+
+    #[test]
+    fn cancel() {
+        let token = Token::default();
+        assert!(!is_cancelled());
+        with(token.clone(), || {
+            assert!(!is_cancelled());
+            token.cancel();
+            assert!(is_cancelled());
+
+            let token = current();
+            let spawned = std::thread::spawn(move || with(token, is_cancelled));
+            assert!(spawned.join().unwrap());
+        });
+        assert!(!is_cancelled());
+    }
+}
@@ -0,0 +1,115 @@
+use std::{collections::HashMap, fmt::Display, io, path::Path};
+
+use crate::{answer::Answer, cli, registry::Part};
+
+/// Known-correct answers, read from a file with one `<day><part> <answer>` entry per line.
+///
+/// ```text
+/// # Comments and empty lines are ignored
+/// 1a 1097
+/// 1b 7101
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u8, Part), String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Status {
+    pub fn compare(answer: &Answer, expected: &str) -> Self {
+        if *answer == expected.parse().unwrap() {
+            Status::Pass
+        } else {
+            Status::Fail {
+                expected: expected.to_string(),
+            }
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail { expected } => write!(f, "FAIL (expected `{expected}`)"),
+            Status::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+impl Answers {
+    /// Reads the answers file at `path`. A missing file is treated as an empty one.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content).map_err(|err| format!("{}:{err}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("failed to read `{}`: {err}", path.display())),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut answers = HashMap::new();
+        for (idx, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = || format!("{}: expected `<day><part> <answer>`, got `{line}`", idx + 1);
+            let (puzzle, answer) = line.split_once(char::is_whitespace).ok_or_else(invalid)?;
+            let (day, part) = cli::parse_day_part(puzzle).ok_or_else(invalid)?;
+            if answers
+                .insert((day, part), answer.trim().to_string())
+                .is_some()
+            {
+                return Err(format!("{}: duplicate answer for {day}{part}", idx + 1));
+            }
+        }
+
+        Ok(Self { answers })
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Status {
+        match self.answers.get(&(day, part)) {
+            Some(expected) => Status::compare(answer, expected),
+            None => Status::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Status};
+    use crate::registry::Part;
+
+    #[test]
+    fn check() {
+        let answers = Answers::parse("# Day 1\n1a 3\n1b   6\n\n5a 3\n").unwrap();
+
+        assert_eq!(answers.check(1, Part::A, &3_u64.into()), Status::Pass);
+        assert_eq!(answers.check(1, Part::B, &6_u64.into()), Status::Pass);
+        assert_eq!(
+            answers.check(5, Part::A, &4_u64.into()),
+            Status::Fail {
+                expected: "3".to_string()
+            }
+        );
+        assert_eq!(answers.check(5, Part::B, &14_u64.into()), Status::Unknown);
+    }
+
+    #[test]
+    fn invalid_answers() {
+        assert!(Answers::parse("1 3\n").is_err());
+        assert!(Answers::parse("1a\n").is_err());
+        assert!(Answers::parse("xa 3\n").is_err());
+        assert_eq!(
+            Answers::parse("1a 3\n1a 4\n").unwrap_err(),
+            "2: duplicate answer for 1a"
+        );
+    }
+}
@@ -0,0 +1,1082 @@
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    bench,
+    input::{Inputs, Source},
+    output::Format,
+    registry::{self, Day, Event, Part},
+};
+
+pub const USAGE: &str = "\
+Usage: aoc2025 [OPTIONS] [DAYS...]
+       aoc2025 submit [SERVER OPTIONS] PUZZLE [ANSWER]
+       aoc2025 fetch [SERVER OPTIONS] [DAYS...]
+       aoc2025 new-day [--event NAME] [--src DIR] N
+       aoc2025 repl [--event NAME] [--input-dir DIR] [--input PATH] [--example N] DAY
+       aoc2025 bisect [--event NAME] [--input-dir DIR] [--input PATH] [--output PATH] PUZZLE
+
+Runs the selected puzzles of an event, or all of them if no days are given.
+
+Commands:
+    submit               submit the answer to a puzzle like `7a` to the puzzle server, solving
+                         the puzzle if no ANSWER is given; the session cookie is read from
+                         `AOC_SESSION`, and the verdict is recorded in the submissions log
+    fetch                download the inputs of the selected days, or of all of them, into the
+                         input directory; inputs that are already there are not downloaded
+                         again, and their checksums are recorded in `checksums.txt`
+    new-day              create the module of day N with stubs for its puzzles, and register it
+                         with the runner; existing files are never overwritten, and the module
+                         is written to `DIR/EVENT/dayN.rs` (default: the `src` directory of the
+                         crate), where EVENT is the module of the event, like `y2025`
+    repl                 load the input of a day once, and explore it with commands read from
+                         stdin: solve its puzzles, change their parameters, look at the parsed
+                         input and time any of these; `--input` and `--example N` load another
+                         input instead, and `help` lists the commands
+    bisect               find a small subset of the lines of the input of a puzzle like `10b`
+                         that still makes it panic at the same place, or fail with an error,
+                         like the whole input does; the lines are written to `--output`
+                         (default: `day_N_PART_reproducer.txt` in the input directory)
+
+Options:
+    --event NAME         the event to run the puzzles of, like `2025` (default: the latest one)
+    --list               list the available days instead of running them
+    --input-dir DIR      read inputs from `DIR/EVENT/day_N_a_input.txt`, where EVENT is the
+                         name of the event (default: `.`)
+    --input [N=]PATH     read the input of day N from PATH, or from stdin if PATH is `-`;
+                         N may be left out if only a single day is selected
+    --example            solve the examples from the puzzle descriptions instead of the
+                         inputs, and check their answers
+    --bench              benchmark the selected puzzles instead of only solving them
+    --warmup N           untimed runs before benchmarking (default: 3)
+    --iterations N       timed runs when benchmarking (default: 20)
+    --check              compare the solutions against the known-correct answers
+    --answers PATH       read the known-correct answers from PATH
+                         (default: `answers.txt` in the input directory of the event)
+    --format FORMAT      print the results as `text`, `json` or `csv` (default: `text`)
+    --jobs N             solve up to N puzzles at the same time (default: 1);
+                         benchmarks are most reliable with a single job
+    --timeout SECONDS    give up on a puzzle if it takes longer than this; solvers keep running
+                         in the background until they notice or all other puzzles are done
+    --memory             report the heap usage of each puzzle
+    --history PATH       append the timings of the puzzles solved on their inputs to PATH, along
+                         with the commit, the build profile and how they were measured
+                         (default: `timings.txt` in the input directory of the event)
+    --compare            flag the puzzles that got slower than their baseline, the median of
+                         their latest timings in the history that were measured the same way:
+                         with the same build profile, `--bench` or not, `--memory` or not, and
+                         as many puzzles solved at the same time
+    --threshold PERCENT  how much slower than its baseline a puzzle may get (default: 20)
+    --cached             reuse the answers to puzzles whose input and solver version did not
+                         change since they were last solved, instead of solving them again
+                         (ignored with `--bench` and `--memory`, which need the puzzles solved)
+    --cache PATH         where the answers to the puzzles solved on their inputs are kept
+                         (default: `cache.txt` in the input directory of the event)
+    --watch              re-run the selected puzzles whenever their input or source changes,
+                         showing how the answers and timings differ from the previous run
+
+Server options:
+    --event NAME         the event of the puzzles, as above
+    --input-dir DIR      the input directory, as above
+    --input PATH         read the input to submit an answer for from PATH
+    --base-url URL       the puzzle server (default: `AOC_BASE_URL`, or `https://adventofcode.com`)
+    --log PATH           record the verdicts on submitted answers in PATH
+                         (default: `submissions.txt` in the input directory of the event)
+
+DAYS can be given as:
+    7        both puzzles of day 7
+    7a, 7b   only the first or second puzzle of day 7
+    3..=6    both puzzles of days 3 to 6 (inclusive)
+    3..6     both puzzles of days 3 to 5 (exclusive)
+
+Logging:
+    Diagnostics are written to stderr at the levels set in `AOC_LOG`, which are `error`, `warn`,
+    `info`, `debug`, `trace` and `off`. A level on its own applies to everything, and `TARGET=LEVEL`
+    to the messages of one module, like `day10` for day 10 or `runner` for the runner. The most
+    specific target wins, so `AOC_LOG=off,day7=debug` traces day 7 alone (default: `warn`).";
+
+#[derive(Debug)]
+pub struct Args {
+    pub event: &'static Event,
+    /// The selected puzzles, in day order.
+    pub selection: Vec<(&'static Day, Part)>,
+    pub list: bool,
+    pub inputs: Inputs,
+    /// Whether to solve the examples of the selected puzzles instead of their inputs.
+    pub example: bool,
+    /// Set if the selected puzzles should be benchmarked.
+    pub bench: Option<bench::Config>,
+    pub check: bool,
+    pub answers: PathBuf,
+    pub format: Format,
+    /// The number of puzzles that may be solved at the same time.
+    pub jobs: usize,
+    /// How long a puzzle may take before it is given up on.
+    pub timeout: Option<Duration>,
+    /// Whether to track the heap usage of the selected puzzles.
+    pub memory: bool,
+    pub history: PathBuf,
+    /// Set to the threshold in percent if the puzzles should be compared to their baseline.
+    pub compare: Option<f64>,
+    /// Whether to reuse cached answers instead of solving the puzzles again.
+    pub cached: bool,
+    pub cache: PathBuf,
+    pub watch: bool,
+}
+
+/// The arguments of the `submit` command.
+#[derive(Debug)]
+pub struct SubmitArgs {
+    pub event: &'static Event,
+    pub day: &'static Day,
+    pub part: Part,
+    /// The answer to submit. The puzzle is solved if this is not set.
+    pub answer: Option<String>,
+    pub inputs: Inputs,
+    pub base_url: Option<String>,
+    pub log: PathBuf,
+}
+
+/// The arguments of the `fetch` command.
+#[derive(Debug)]
+pub struct FetchArgs {
+    pub event: &'static Event,
+    pub days: Vec<&'static Day>,
+    pub inputs: Inputs,
+    pub base_url: Option<String>,
+}
+
+/// The arguments of the `repl` command.
+#[derive(Debug)]
+pub struct ReplArgs {
+    pub event: &'static Event,
+    pub day: &'static Day,
+    pub inputs: Inputs,
+    /// The index of the example to load instead of the input.
+    pub example: Option<usize>,
+}
+
+/// The arguments of the `bisect` command.
+#[derive(Debug)]
+pub struct BisectArgs {
+    pub event: &'static Event,
+    pub day: &'static Day,
+    pub part: Part,
+    pub inputs: Inputs,
+    /// Where the reproducer is written to.
+    pub output: PathBuf,
+}
+
+/// The arguments of the `new-day` command.
+#[derive(Debug)]
+pub struct NewDayArgs {
+    pub event: &'static Event,
+    pub day: u8,
+    pub src: PathBuf,
+}
+
+#[derive(Debug)]
+pub enum Command {
+    Run(Args),
+    Submit(SubmitArgs),
+    Fetch(FetchArgs),
+    NewDay(NewDayArgs),
+    Repl(ReplArgs),
+    Bisect(BisectArgs),
+}
+
+/// Parses the arguments of any command, where running puzzles is the default.
+pub fn parse_command(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter().peekable();
+    match args.peek().map(String::as_str) {
+        Some("submit") => {
+            let _ = args.next();
+            parse_submit(args).map(Command::Submit)
+        }
+        Some("fetch") => {
+            let _ = args.next();
+            parse_fetch(args).map(Command::Fetch)
+        }
+        Some("new-day") => {
+            let _ = args.next();
+            parse_new_day(args).map(Command::NewDay)
+        }
+        Some("repl") => {
+            let _ = args.next();
+            parse_repl(args).map(Command::Repl)
+        }
+        Some("bisect") => {
+            let _ = args.next();
+            parse_bisect(args).map(Command::Bisect)
+        }
+        _ => parse(args).map(Command::Run),
+    }
+}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut event = None;
+    // The days can only be checked once the event is known.
+    let mut selectors = Vec::new();
+    let mut list = false;
+    let mut inputs = Inputs::default();
+    // Inputs without a day, which can only be resolved once the selection is known.
+    let mut unassigned_input = None;
+    let mut example = false;
+    let mut bench = false;
+    let mut bench_config = bench::Config::default();
+    let mut check = false;
+    let mut answers = None;
+    let mut format = Format::default();
+    let mut jobs = 1;
+    let mut timeout = None;
+    let mut memory = false;
+    let mut history = None;
+    let mut compare = false;
+    let mut threshold = 20.0;
+    let mut cached = false;
+    let mut cache = None;
+    let mut watch = false;
+    let mut args = Options::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Option(option) => match option.as_str() {
+                "--event" => event = Some(args.value()?),
+                "--list" => list = true,
+                "--input-dir" => inputs.set_dir(args.value()?),
+                "--input" => {
+                    let value = args.value()?;
+                    match value.split_once('=') {
+                        Some((number, path)) => {
+                            let number = parse_day(number, &value)?;
+                            inputs.set_override(number, path.into());
+                        }
+                        None => unassigned_input = Some(value),
+                    }
+                }
+                "--example" => example = true,
+                "--bench" => bench = true,
+                "--warmup" => bench_config.warmup = parse_number(&args.value()?, &option)?,
+                "--iterations" => bench_config.iterations = parse_number(&args.value()?, &option)?,
+                "--check" => check = true,
+                "--answers" => answers = Some(PathBuf::from(args.value()?)),
+                "--format" => format = args.value()?.parse()?,
+                "--jobs" => {
+                    jobs = parse_number(&args.value()?, &option)?;
+                    if jobs == 0 {
+                        return Err("`--jobs` needs at least one job".to_string());
+                    }
+                }
+                "--timeout" => {
+                    let value = args.value()?;
+                    timeout = match value.parse().map(Duration::try_from_secs_f64) {
+                        Ok(Ok(timeout)) if !timeout.is_zero() => Some(timeout),
+                        _ => return Err(format!("invalid timeout: `{value}`")),
+                    };
+                }
+                "--memory" => memory = true,
+                "--history" => history = Some(PathBuf::from(args.value()?)),
+                "--compare" => compare = true,
+                "--threshold" => {
+                    let value = args.value()?;
+                    threshold = match value.parse() {
+                        Ok(threshold) if threshold >= 0.0 => threshold,
+                        _ => return Err(format!("invalid threshold: `{value}`")),
+                    };
+                }
+                "--cached" => cached = true,
+                "--cache" => cache = Some(PathBuf::from(args.value()?)),
+                "--watch" => watch = true,
+                _ => return Err(args.unknown()),
+            },
+            Arg::Positional(arg) => selectors.push(arg),
+        }
+    }
+
+    let event = parse_event(event)?;
+    inputs.set_event(event.name);
+    let mut selected = BTreeSet::new();
+    for selector in &selectors {
+        parse_selector(event, selector, &mut selected)?;
+    }
+    let selection: Vec<_> = if selected.is_empty() {
+        event
+            .days
+            .iter()
+            .flat_map(|day| day.parts().map(move |part| (day, part)))
+            .collect()
+    } else {
+        selected
+            .into_iter()
+            .map(|(number, part)| (event.find(number).unwrap(), part))
+            .collect()
+    };
+
+    if let Some(path) = unassigned_input {
+        let mut days: Vec<_> = selection.iter().map(|(day, _)| day.number).collect();
+        days.dedup();
+        let [number] = days[..] else {
+            return Err(format!(
+                "`--input {path}` needs a day (`--input N={path}`) when more than one day is selected"
+            ));
+        };
+        inputs.set_override(number, path.as_str().into());
+    }
+
+    Ok(Args {
+        event,
+        selection,
+        list,
+        example,
+        bench: bench.then_some(bench_config),
+        check,
+        answers: answers.unwrap_or_else(|| inputs.dir().join("answers.txt")),
+        history: history.unwrap_or_else(|| inputs.dir().join("timings.txt")),
+        cache: cache.unwrap_or_else(|| inputs.dir().join("cache.txt")),
+        inputs,
+        format,
+        jobs,
+        timeout,
+        memory,
+        compare: compare.then_some(threshold),
+        cached,
+        watch,
+    })
+}
+
+fn parse_submit(args: impl IntoIterator<Item = String>) -> Result<SubmitArgs, String> {
+    let mut event = None;
+    let mut positional = Vec::new();
+    let mut inputs = Inputs::default();
+    let mut input = None;
+    let mut base_url = None;
+    let mut log = None;
+    let mut args = Options::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Option(option) => match option.as_str() {
+                "--event" => event = Some(args.value()?),
+                "--input-dir" => inputs.set_dir(args.value()?),
+                "--input" => input = Some(args.value()?),
+                "--base-url" => base_url = Some(args.value()?),
+                "--log" => log = Some(PathBuf::from(args.value()?)),
+                _ => return Err(args.unknown()),
+            },
+            Arg::Positional(arg) => positional.push(arg),
+        }
+    }
+
+    let (puzzle, answer) = match &positional[..] {
+        [puzzle] => (puzzle, None),
+        [puzzle, answer] => (puzzle, Some(answer.clone())),
+        _ => return Err("`submit` needs a puzzle like `7a`, and optionally an answer".to_string()),
+    };
+    let event = parse_event(event)?;
+    inputs.set_event(event.name);
+    let mut selected = BTreeSet::new();
+    parse_selector(event, puzzle, &mut selected)?;
+    let Some(&(number, part)) = selected.first().filter(|_| selected.len() == 1) else {
+        return Err(format!(
+            "`submit` needs a single puzzle like `7a`, got `{puzzle}`"
+        ));
+    };
+    if let Some(path) = input {
+        inputs.set_override(number, path.as_str().into());
+    }
+
+    Ok(SubmitArgs {
+        event,
+        day: event.find(number).unwrap(),
+        part,
+        answer,
+        log: log.unwrap_or_else(|| inputs.dir().join("submissions.txt")),
+        inputs,
+        base_url,
+    })
+}
+
+fn parse_fetch(args: impl IntoIterator<Item = String>) -> Result<FetchArgs, String> {
+    let mut event = None;
+    let mut selectors = Vec::new();
+    let mut inputs = Inputs::default();
+    let mut base_url = None;
+    let mut args = Options::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Option(option) => match option.as_str() {
+                "--event" => event = Some(args.value()?),
+                "--input-dir" => inputs.set_dir(args.value()?),
+                "--base-url" => base_url = Some(args.value()?),
+                _ => return Err(args.unknown()),
+            },
+            Arg::Positional(arg) => selectors.push(arg),
+        }
+    }
+
+    let event = parse_event(event)?;
+    inputs.set_event(event.name);
+    let mut selected = BTreeSet::new();
+    for selector in &selectors {
+        parse_selector(event, selector, &mut selected)?;
+    }
+    let mut days: Vec<_> = if selected.is_empty() {
+        event.days.iter().collect()
+    } else {
+        selected
+            .into_iter()
+            .map(|(number, _)| event.find(number).unwrap())
+            .collect()
+    };
+    days.dedup_by_key(|day| day.number);
+
+    Ok(FetchArgs {
+        event,
+        days,
+        inputs,
+        base_url,
+    })
+}
+
+fn parse_new_day(args: impl IntoIterator<Item = String>) -> Result<NewDayArgs, String> {
+    let mut event = None;
+    let mut day = None;
+    let mut src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let mut args = Options::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Option(option) => match option.as_str() {
+                "--event" => event = Some(args.value()?),
+                "--src" => src = PathBuf::from(args.value()?),
+                _ => return Err(args.unknown()),
+            },
+            Arg::Positional(arg) => {
+                if day.is_some() {
+                    return Err(format!("unexpected argument: `{arg}`"));
+                }
+                match arg.parse() {
+                    Ok(0) | Err(_) => return Err(format!("invalid day: `{arg}`")),
+                    Ok(number) => day = Some(number),
+                }
+            }
+        }
+    }
+    let day = day.ok_or("missing the day to create")?;
+
+    Ok(NewDayArgs {
+        event: parse_event(event)?,
+        day,
+        src,
+    })
+}
+
+fn parse_bisect(args: impl IntoIterator<Item = String>) -> Result<BisectArgs, String> {
+    let mut event = None;
+    let mut puzzle = None;
+    let mut inputs = Inputs::default();
+    let mut input = None;
+    let mut output = None;
+    let mut args = Options::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Option(option) => match option.as_str() {
+                "--event" => event = Some(args.value()?),
+                "--input-dir" => inputs.set_dir(args.value()?),
+                "--input" => input = Some(args.value()?),
+                "--output" => output = Some(PathBuf::from(args.value()?)),
+                _ => return Err(args.unknown()),
+            },
+            Arg::Positional(arg) => {
+                if puzzle.is_some() {
+                    return Err(format!("unexpected argument: `{arg}`"));
+                }
+                puzzle = Some(arg);
+            }
+        }
+    }
+
+    let puzzle = puzzle.ok_or("`bisect` needs a puzzle like `10b`")?;
+    let event = parse_event(event)?;
+    inputs.set_event(event.name);
+    let mut selected = BTreeSet::new();
+    parse_selector(event, &puzzle, &mut selected)?;
+    let Some(&(number, part)) = selected.first().filter(|_| selected.len() == 1) else {
+        return Err(format!(
+            "`bisect` needs a single puzzle like `10b`, got `{puzzle}`"
+        ));
+    };
+    if let Some(path) = input {
+        inputs.set_override(number, path.as_str().into());
+    }
+
+    Ok(BisectArgs {
+        event,
+        day: event.find(number).unwrap(),
+        part,
+        output: output.unwrap_or_else(|| {
+            inputs
+                .dir()
+                .join(format!("day_{number}_{part}_reproducer.txt"))
+        }),
+        inputs,
+    })
+}
+
+fn parse_repl(args: impl IntoIterator<Item = String>) -> Result<ReplArgs, String> {
+    let mut event = None;
+    let mut day = None;
+    let mut inputs = Inputs::default();
+    let mut input = None;
+    let mut example = None;
+    let mut args = Options::new(args);
+    while let Some(arg) = args.next() {
+        match arg {
+            Arg::Option(option) => match option.as_str() {
+                "--event" => event = Some(args.value()?),
+                "--input-dir" => inputs.set_dir(args.value()?),
+                "--input" => input = Some(Source::from(args.value()?.as_str())),
+                "--example" => match parse_number(&args.value()?, &option)? {
+                    0 => return Err("examples are counted from 1".to_string()),
+                    number => example = Some(number - 1),
+                },
+                _ => return Err(args.unknown()),
+            },
+            Arg::Positional(arg) => {
+                if day.is_some() {
+                    return Err(format!("unexpected argument: `{arg}`"));
+                }
+                day = Some(arg);
+            }
+        }
+    }
+
+    let day = day.ok_or("`repl` needs the day to explore")?;
+    let event = parse_event(event)?;
+    inputs.set_event(event.name);
+    let number = parse_day(&day, &day)?;
+    let Some(day) = event.find(number) else {
+        return Err(format!(
+            "day {number} is not available in event {}",
+            event.name
+        ));
+    };
+    if let Some(idx) = example
+        && idx >= day.puzzle.examples().len()
+    {
+        return Err(format!(
+            "day {number} has {} examples, not {}",
+            day.puzzle.examples().len(),
+            idx + 1
+        ));
+    }
+    match input {
+        // The commands are read from stdin, so the input can't be.
+        Some(Source::Stdin) => return Err("`repl` can't read the input from stdin".to_string()),
+        Some(source) => inputs.set_override(number, source),
+        None => {}
+    }
+
+    Ok(ReplArgs {
+        event,
+        day,
+        inputs,
+        example,
+    })
+}
+
+/// An argument on the command line.
+enum Arg {
+    /// An option like `--jobs`, whose value is read with [`Options::value`].
+    Option(String),
+    Positional(String),
+}
+
+/// Reads the arguments of a command one at a time, along with the values of options, which are
+/// given as `--option=value` or `--option value`.
+struct Options<I> {
+    args: I,
+    /// The last option, as it was given.
+    arg: String,
+    option: String,
+    /// The value given along with the last option, as in `--option=value`.
+    inline_value: Option<String>,
+}
+
+impl<I: Iterator<Item = String>> Options<I> {
+    fn new(args: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            args: args.into_iter(),
+            arg: String::new(),
+            option: String::new(),
+            inline_value: None,
+        }
+    }
+
+    fn next(&mut self) -> Option<Arg> {
+        let arg = self.args.next()?;
+        let (option, inline_value) = match arg.split_once('=') {
+            Some((option, value)) if option.starts_with("--") => (option, Some(value.to_string())),
+            _ if arg.starts_with("--") => (arg.as_str(), None),
+            _ => return Some(Arg::Positional(arg)),
+        };
+        self.option = option.to_string();
+        self.inline_value = inline_value;
+        self.arg = arg;
+
+        Some(Arg::Option(self.option.clone()))
+    }
+
+    /// The value of the last option.
+    fn value(&mut self) -> Result<String, String> {
+        self.inline_value
+            .take()
+            .or_else(|| self.args.next())
+            .ok_or_else(|| format!("missing value for `{}`", self.option))
+    }
+
+    /// The error for the last option if the command doesn't know it.
+    fn unknown(&self) -> String {
+        format!("unknown option: `{}`", self.arg)
+    }
+}
+
+/// The event with the given name, or the default one.
+fn parse_event(name: Option<String>) -> Result<&'static Event, String> {
+    match name {
+        Some(name) => registry::event(&name).ok_or_else(|| format!("unknown event: `{name}`")),
+        None => Ok(registry::default_event()),
+    }
+}
+
+fn parse_selector(
+    event: &'static Event,
+    arg: &str,
+    selected: &mut BTreeSet<(u8, Part)>,
+) -> Result<(), String> {
+    if let Some((start, end)) = arg.split_once("..") {
+        let (end, inclusive) = match end.strip_prefix('=') {
+            Some(end) => (end, true),
+            None => (end, false),
+        };
+        let start = parse_day(start, arg)?;
+        let end = parse_day(end, arg)?;
+        let days = if inclusive {
+            start..=end
+        } else {
+            start..=end.saturating_sub(1)
+        };
+        if days.is_empty() {
+            return Err(format!("empty range of days: `{arg}`"));
+        }
+        for number in days {
+            select_day(event, number, None, selected)?;
+        }
+
+        return Ok(());
+    }
+
+    let (number, part) = match parse_day_part(arg) {
+        Some((number, part)) => (number, Some(part)),
+        None => (parse_day(arg, arg)?, None),
+    };
+
+    select_day(event, number, part, selected)
+}
+
+/// Parses a puzzle like `7a` into its day and part.
+pub fn parse_day_part(puzzle: &str) -> Option<(u8, Part)> {
+    let (number, part) = match puzzle.as_bytes().last() {
+        Some(b'a') => (&puzzle[..puzzle.len() - 1], Part::A),
+        Some(b'b') => (&puzzle[..puzzle.len() - 1], Part::B),
+        _ => return None,
+    };
+
+    Some((number.parse().ok()?, part))
+}
+
+fn parse_day(number: &str, arg: &str) -> Result<u8, String> {
+    number
+        .parse()
+        .map_err(|_| format!("invalid day selection: `{arg}`"))
+}
+
+fn parse_number(value: &str, option: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid number for `{option}`: `{value}`"))
+}
+
+fn select_day(
+    event: &'static Event,
+    number: u8,
+    part: Option<Part>,
+    selected: &mut BTreeSet<(u8, Part)>,
+) -> Result<(), String> {
+    let Some(day) = event.find(number) else {
+        return Err(format!(
+            "day {number} is not available in event {}",
+            event.name
+        ));
+    };
+    match part {
+        Some(part) => {
+            if !day.has_part(part) {
+                return Err(format!("day {number} has no puzzle `{part}`"));
+            }
+            let _ = selected.insert((number, part));
+        }
+        None => selected.extend(day.parts().map(|part| (number, part))),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use super::{Command, parse, parse_command, parse_day_part};
+    use crate::{
+        input::Source,
+        output::Format,
+        registry::{self, Part},
+    };
+
+    fn selection(args: &[&str]) -> Result<Vec<(u8, Part)>, String> {
+        parse(args.iter().map(|a| a.to_string())).map(|args| {
+            args.selection
+                .into_iter()
+                .map(|(day, part)| (day.number, part))
+                .collect()
+        })
+    }
+
+    #[test]
+    fn selectors() {
+        assert_eq!(selection(&["7"]), Ok(vec![(7, Part::A), (7, Part::B)]));
+        assert_eq!(selection(&["7b"]), Ok(vec![(7, Part::B)]));
+        assert_eq!(
+            selection(&["4..=5", "3a"]),
+            Ok(vec![
+                (3, Part::A),
+                (4, Part::A),
+                (4, Part::B),
+                (5, Part::A),
+                (5, Part::B)
+            ])
+        );
+        assert_eq!(selection(&["11..13"]).unwrap().len(), 3);
+        assert_eq!(selection(&[]).unwrap().len(), 23);
+    }
+
+    #[test]
+    fn invalid_selectors() {
+        assert!(selection(&["13"]).is_err());
+        assert!(selection(&["12b"]).is_err());
+        assert!(selection(&["6..=3"]).is_err());
+        assert!(selection(&["seven"]).is_err());
+        assert!(selection(&["--frobnicate"]).is_err());
+    }
+
+    #[test]
+    fn options() {
+        let parse = |args: &[&str]| parse(args.iter().map(|a| a.to_string()));
+        assert_eq!(parse(&["--jobs=3"]).unwrap().jobs, 3);
+        assert_eq!(parse(&["--jobs", "3"]).unwrap().jobs, 3);
+        assert_eq!(
+            parse(&["--jobs"]).unwrap_err(),
+            "missing value for `--jobs`"
+        );
+        assert_eq!(
+            parse(&["--frobnicate=3"]).unwrap_err(),
+            "unknown option: `--frobnicate=3`"
+        );
+    }
+
+    #[test]
+    fn day_parts() {
+        assert_eq!(parse_day_part("7a"), Some((7, Part::A)));
+        assert_eq!(parse_day_part("12b"), Some((12, Part::B)));
+        assert_eq!(parse_day_part("7"), None);
+        assert_eq!(parse_day_part("7c"), None);
+        assert_eq!(parse_day_part("a"), None);
+        assert_eq!(parse_day_part("300a"), None);
+    }
+
+    #[test]
+    fn events() {
+        let args = parse(["7b", "--event", "2025"].into_iter().map(String::from)).unwrap();
+        assert_eq!(args.event.name, "2025");
+        assert_eq!(args.selection.len(), 1);
+        assert_eq!(
+            parse([].into_iter()).unwrap().event.name,
+            registry::default_event().name
+        );
+        assert!(parse(["--event=1999"].into_iter().map(String::from)).is_err());
+    }
+
+    #[test]
+    fn inputs() {
+        let args = parse(
+            ["--input-dir=inputs/bob", "--input", "7=-", "3..=7"]
+                .into_iter()
+                .map(String::from),
+        )
+        .unwrap();
+        assert_eq!(
+            args.inputs.source(3),
+            Source::Path(PathBuf::from("inputs/bob/2025/day_3_a_input.txt"))
+        );
+        assert_eq!(args.inputs.source(7), Source::Stdin);
+
+        let args = parse(["9b", "--input", "nine.txt"].into_iter().map(String::from)).unwrap();
+        assert_eq!(
+            args.inputs.source(9),
+            Source::Path(PathBuf::from("nine.txt"))
+        );
+
+        assert!(parse(["--input", "-"].into_iter().map(String::from)).is_err());
+        assert!(parse(["--input-dir"].into_iter().map(String::from)).is_err());
+    }
+
+    #[test]
+    fn example() {
+        assert!(
+            parse(["--example"].into_iter().map(String::from))
+                .unwrap()
+                .example
+        );
+        assert!(!parse([].into_iter()).unwrap().example);
+    }
+
+    #[test]
+    fn timeout() {
+        let args = parse(["--timeout", "2.5"].into_iter().map(String::from)).unwrap();
+        assert_eq!(args.timeout, Some(Duration::from_millis(2500)));
+        assert_eq!(parse([].into_iter()).unwrap().timeout, None);
+        assert!(parse(["--timeout=0"].into_iter().map(String::from)).is_err());
+        assert!(parse(["--timeout=-1"].into_iter().map(String::from)).is_err());
+    }
+
+    #[test]
+    fn memory() {
+        assert!(
+            parse(["--memory"].into_iter().map(String::from))
+                .unwrap()
+                .memory
+        );
+        assert!(!parse([].into_iter()).unwrap().memory);
+    }
+
+    #[test]
+    fn watch() {
+        assert!(
+            parse(["--watch"].into_iter().map(String::from))
+                .unwrap()
+                .watch
+        );
+        assert!(!parse([].into_iter()).unwrap().watch);
+    }
+
+    #[test]
+    fn answers() {
+        let args = parse(
+            ["--input-dir", "inputs/carol"]
+                .into_iter()
+                .map(String::from),
+        )
+        .unwrap();
+        assert_eq!(args.answers, PathBuf::from("inputs/carol/2025/answers.txt"));
+
+        let args = parse(
+            ["--check", "--answers", "known.txt"]
+                .into_iter()
+                .map(String::from),
+        )
+        .unwrap();
+        assert!(args.check);
+        assert_eq!(args.answers, PathBuf::from("known.txt"));
+    }
+
+    #[test]
+    fn history() {
+        let args = parse(["--input-dir=inputs/frank"].into_iter().map(String::from)).unwrap();
+        assert_eq!(args.history, PathBuf::from("inputs/frank/2025/timings.txt"));
+        assert_eq!(args.compare, None);
+
+        let args = parse(
+            ["--compare", "--history", "t.txt", "--threshold", "5.5"]
+                .into_iter()
+                .map(String::from),
+        )
+        .unwrap();
+        assert_eq!(args.history, PathBuf::from("t.txt"));
+        assert_eq!(args.compare, Some(5.5));
+        assert_eq!(
+            parse(["--compare"].into_iter().map(String::from))
+                .unwrap()
+                .compare,
+            Some(20.0)
+        );
+        assert!(parse(["--threshold=-1"].into_iter().map(String::from)).is_err());
+    }
+
+    #[test]
+    fn cache() {
+        let args = parse(["--input-dir=inputs/frank"].into_iter().map(String::from)).unwrap();
+        assert_eq!(args.cache, PathBuf::from("inputs/frank/2025/cache.txt"));
+        assert!(!args.cached);
+
+        let args = parse(["--cached", "--cache=c.txt"].into_iter().map(String::from)).unwrap();
+        assert_eq!(args.cache, PathBuf::from("c.txt"));
+        assert!(args.cached);
+    }
+
+    #[test]
+    fn format() {
+        let args = parse(["--format", "json"].into_iter().map(String::from)).unwrap();
+        assert_eq!(args.format, Format::Json);
+        assert_eq!(parse([].into_iter()).unwrap().format, Format::Text);
+        assert!(parse(["--format=yaml"].into_iter().map(String::from)).is_err());
+    }
+
+    #[test]
+    fn jobs() {
+        assert_eq!(parse([].into_iter()).unwrap().jobs, 1);
+        let args = parse(["--jobs", "8"].into_iter().map(String::from)).unwrap();
+        assert_eq!(args.jobs, 8);
+        assert!(parse(["--jobs", "0"].into_iter().map(String::from)).is_err());
+    }
+
+    #[test]
+    fn bench() {
+        let args = parse(["--warmup=1", "--bench"].into_iter().map(String::from)).unwrap();
+        let config = args.bench.unwrap();
+        assert_eq!(config.warmup, 1);
+        assert_eq!(config.iterations, 20);
+
+        let args = parse(["--iterations", "5"].into_iter().map(String::from)).unwrap();
+        assert!(args.bench.is_none());
+        assert!(parse(["--iterations", "many"].into_iter().map(String::from)).is_err());
+    }
+
+    #[test]
+    fn submit() {
+        let command = parse_command(
+            [
+                "submit",
+                "7b",
+                "--input-dir",
+                "inputs/dave",
+                "--base-url=http://localhost:8080",
+            ]
+            .into_iter()
+            .map(String::from),
+        )
+        .unwrap();
+        let Command::Submit(args) = command else {
+            panic!("expected the submit command, got {command:?}");
+        };
+        assert_eq!((args.day.number, args.part), (7, Part::B));
+        assert_eq!(args.answer, None);
+        assert_eq!(args.base_url.as_deref(), Some("http://localhost:8080"));
+        assert_eq!(args.log, PathBuf::from("inputs/dave/2025/submissions.txt"));
+
+        let submit = |args: &[&str]| parse_command(args.iter().map(|a| a.to_string()));
+        assert!(matches!(
+            submit(&["submit", "3a", "357"]),
+            Ok(Command::Submit(args)) if args.answer.as_deref() == Some("357")
+        ));
+        assert!(submit(&["submit", "7"]).is_err());
+        assert!(submit(&["submit"]).is_err());
+        assert!(submit(&["submit", "12b", "1"]).is_err());
+        assert!(matches!(submit(&["7"]), Ok(Command::Run(_))));
+    }
+
+    #[test]
+    fn fetch() {
+        let fetch = |args: &[&str]| match parse_command(args.iter().map(|a| a.to_string())) {
+            Ok(Command::Fetch(args)) => Ok(args),
+            Ok(command) => panic!("expected the fetch command, got {command:?}"),
+            Err(err) => Err(err),
+        };
+
+        let args = fetch(&["fetch", "3..=4", "4b", "--input-dir", "inputs/erin"]).unwrap();
+        let days: Vec<_> = args.days.iter().map(|day| day.number).collect();
+        assert_eq!(days, [3, 4]);
+        assert_eq!(args.inputs.dir(), PathBuf::from("inputs/erin/2025"));
+        assert_eq!(fetch(&["fetch"]).unwrap().days.len(), 12);
+        assert!(fetch(&["fetch", "13"]).is_err());
+        assert!(fetch(&["fetch", "--event", "1999"]).is_err());
+    }
+
+    #[test]
+    fn repl() {
+        let repl = |args: &[&str]| match parse_command(args.iter().map(|a| a.to_string())) {
+            Ok(Command::Repl(args)) => Ok(args),
+            Ok(command) => panic!("expected the repl command, got {command:?}"),
+            Err(err) => Err(err),
+        };
+
+        let args = repl(&["repl", "8", "--input", "day8.txt"]).unwrap();
+        assert_eq!(args.day.number, 8);
+        assert_eq!(args.example, None);
+        assert_eq!(args.inputs.source(8), Source::Path("day8.txt".into()));
+        assert_eq!(
+            repl(&["repl", "11", "--example=2"]).unwrap().example,
+            Some(1)
+        );
+        assert!(repl(&["repl"]).is_err());
+        assert!(repl(&["repl", "7a"]).is_err());
+        assert!(repl(&["repl", "13"]).is_err());
+        assert!(repl(&["repl", "8", "9"]).is_err());
+        assert!(repl(&["repl", "8", "--example", "0"]).is_err());
+        assert!(repl(&["repl", "8", "--example", "2"]).is_err());
+        assert!(repl(&["repl", "8", "--input", "-"]).is_err());
+    }
+
+    #[test]
+    fn bisect() {
+        let bisect = |args: &[&str]| match parse_command(args.iter().map(|a| a.to_string())) {
+            Ok(Command::Bisect(args)) => Ok(args),
+            Ok(command) => panic!("expected the bisect command, got {command:?}"),
+            Err(err) => Err(err),
+        };
+
+        let args = bisect(&["bisect", "10b", "--input-dir", "inputs/erin"]).unwrap();
+        assert_eq!((args.day.number, args.part), (10, Part::B));
+        assert_eq!(
+            args.output,
+            PathBuf::from("inputs/erin/2025/day_10_b_reproducer.txt")
+        );
+        let args = bisect(&["bisect", "--input=-", "3a", "--output", "r.txt"]).unwrap();
+        assert_eq!(args.inputs.source(3), Source::Stdin);
+        assert_eq!(args.output, PathBuf::from("r.txt"));
+        assert!(bisect(&["bisect"]).is_err());
+        assert!(bisect(&["bisect", "10"]).is_err());
+        assert!(bisect(&["bisect", "10a", "10b"]).is_err());
+    }
+
+    #[test]
+    fn new_day() {
+        let new_day = |args: &[&str]| match parse_command(args.iter().map(|a| a.to_string())) {
+            Ok(Command::NewDay(args)) => Ok(args),
+            Ok(command) => panic!("expected the new-day command, got {command:?}"),
+            Err(err) => Err(err),
+        };
+
+        let args = new_day(&["new-day", "13"]).unwrap();
+        assert_eq!(args.day, 13);
+        assert!(args.src.ends_with("src"));
+        assert_eq!(
+            new_day(&["new-day", "--src", "event/src", "1"])
+                .unwrap()
+                .src,
+            PathBuf::from("event/src")
+        );
+        assert!(new_day(&["new-day"]).is_err());
+        assert!(new_day(&["new-day", "0"]).is_err());
+        assert!(new_day(&["new-day", "13", "14"]).is_err());
+    }
+}
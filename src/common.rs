@@ -0,0 +1,4 @@
+//! Building blocks for the puzzles, shared by all events.
+
+pub mod grid;
+pub mod range;
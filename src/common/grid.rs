@@ -0,0 +1,133 @@
+use std::ops::{Index, IndexMut};
+
+use crate::error::Error;
+
+/// A rectangular grid of tiles, like the maps many puzzles are drawn on.
+///
+/// Positions are `(x, y)`, with `(0, 0)` in the top left corner.
+///
+/// ```
+/// use aoc2025::common::grid::Grid;
+///
+/// let grid = Grid::parse("..@\n.@.\n", |b| Some(b == b'@'))?;
+/// assert_eq!((grid.width(), grid.height()), (3, 2));
+/// assert!(grid[(2, 0)]);
+/// assert_eq!(grid.neighbors((2, 0)).filter(|&position| grid[position]).count(), 1);
+/// # Ok::<(), aoc2025::error::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    tiles: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parses a grid with one row per line, turning each byte into a tile with `tile`.
+    ///
+    /// Fails if the rows differ in length, or if `tile` does not know a byte.
+    pub fn parse(input: &str, mut tile: impl FnMut(u8) -> Option<T>) -> Result<Self, Error> {
+        let mut tiles = Vec::with_capacity(input.len());
+        let mut width = 0;
+        let mut height = 0;
+        for line in input.lines() {
+            if height == 0 {
+                width = line.len();
+            }
+            if line.len() != width {
+                return Err(Error::at(
+                    line,
+                    format!("expected a row of {width} tiles, found {}", line.len()),
+                ));
+            }
+            for (x, b) in line.bytes().enumerate() {
+                let unexpected = || {
+                    let text = line.get(x..).unwrap_or(line);
+                    let c = text.chars().next().unwrap_or(b as char);
+                    Error::at(text, format!("unexpected tile '{c}'"))
+                };
+                tiles.push(tile(b).ok_or_else(unexpected)?);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width,
+            height,
+            tiles,
+        })
+    }
+
+    /// The number of tiles in a row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The tile at `(x, y)`, or `None` outside of the grid.
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.tiles[y * self.width + x])
+    }
+
+    /// All tiles with their positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.tiles
+            .iter()
+            .enumerate()
+            .map(move |(idx, tile)| ((idx % width, idx / width), tile))
+    }
+
+    /// The positions around `(x, y)`, including the diagonal ones, that are inside the grid.
+    pub fn neighbors(&self, (x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        [-1, 0, 1]
+            .into_iter()
+            .flat_map(|dy| [-1, 0, 1].into_iter().map(move |dx| (dx, dy)))
+            .filter(|&offset| offset != (0, 0))
+            .filter_map(move |(dx, dy)| {
+                let x = x.checked_add_signed(dx).filter(|&x| x < width)?;
+                let y = y.checked_add_signed(dy).filter(|&y| y < height)?;
+                Some((x, y))
+            })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(x < self.width, "x = {x} is outside of the grid");
+        &self.tiles[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(x < self.width, "x = {x} is outside of the grid");
+        &mut self.tiles[y * self.width + x]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    #[test]
+    fn grid() {
+        let mut grid = Grid::parse("ab\ncd\nef\n", Some).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[(1, 2)], b'f');
+        assert_eq!(grid.get((2, 0)), None);
+        grid[(0, 1)] = b'x';
+        assert_eq!(grid.positions().nth(2), Some(((0, 1), &b'x')));
+
+        let neighbors: Vec<_> = grid.neighbors((0, 0)).collect();
+        assert_eq!(neighbors, [(1, 0), (0, 1), (1, 1)]);
+        assert_eq!(grid.neighbors((1, 1)).count(), 5);
+    }
+}
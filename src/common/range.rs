@@ -0,0 +1,22 @@
+use std::ops::RangeInclusive;
+
+use crate::error::{self, Error};
+
+/// Parses an inclusive range of numbers written as `start-end`, like `11-22`.
+pub fn parse(value: &str) -> Result<RangeInclusive<usize>, Error> {
+    let (start, end) = error::split_once(value, '-')?;
+
+    Ok(error::number(start)?..=error::number(end)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    #[test]
+    fn ranges() {
+        assert_eq!(parse("11-22"), Ok(11..=22));
+        assert_eq!(parse("5-5"), Ok(5..=5));
+        assert_eq!(parse("5+5").unwrap_err().message, "expected '-' in '5+5'");
+    }
+}
@@ -0,0 +1,120 @@
+use std::{fmt::Display, str::FromStr};
+
+/// A position in an input, with the line and column counted from 1.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Why a puzzle could not be solved, usually because its input is not what the parser expected.
+///
+/// Parsers only see pieces of the input, so an error points at the piece it is about with
+/// [`Error::at`]. Whoever holds the whole input turns that into a line and column with
+/// [`Error::locate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub message: String,
+    pub span: Option<Span>,
+    /// The address of the piece of the input the error is about, until it is located.
+    address: Option<usize>,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            span: None,
+            address: None,
+        }
+    }
+
+    /// An error about `text`, which has to be a slice of the input.
+    pub fn at(text: &str, message: impl Into<String>) -> Self {
+        Self {
+            address: Some(text.as_ptr() as usize),
+            ..Self::new(message)
+        }
+    }
+
+    /// Finds the line and column the error is about in `input`, if it points into it.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if let Some(offset) = self.address.and_then(|address| address.checked_sub(start))
+            && offset <= input.len()
+        {
+            let before = &input[..offset];
+            let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+            self.span = Some(Span {
+                line: before.matches('\n').count() + 1,
+                column: before[line_start..].chars().count() + 1,
+            });
+            self.address = None;
+        }
+
+        self
+    }
+
+    /// Describes the error as one in the input called `name`, like
+    /// `day_7 input:3:12: unexpected tile 'x'`.
+    pub fn describe(&self, name: &str) -> String {
+        match self.span {
+            Some(span) => format!("{name}:{span}: {}", self.message),
+            None => format!("{name}: {}", self.message),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.span {
+            Some(span) => write!(f, "{span}: {}", self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parses a number from a piece of the input.
+pub fn number<T: FromStr>(text: &str) -> Result<T, Error> {
+    text.parse()
+        .map_err(|_| Error::at(text, format!("invalid number '{text}'")))
+}
+
+/// Splits a piece of the input at the first `delimiter`, for inputs like `x,y`.
+pub fn split_once(text: &str, delimiter: char) -> Result<(&str, &str), Error> {
+    text.split_once(delimiter)
+        .ok_or_else(|| Error::at(text, format!("expected '{delimiter}' in '{text}'")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, Span, number};
+
+    #[test]
+    fn locate() {
+        let input = "..S..\n.....\n..^x.\n";
+        let err = Error::at(&input[15..16], "unexpected tile 'x'").locate(input);
+        assert_eq!(err.span, Some(Span { line: 3, column: 4 }));
+        assert_eq!(
+            err.describe("day_7 input"),
+            "day_7 input:3:4: unexpected tile 'x'"
+        );
+
+        // Errors about something else than the input stay where they are.
+        let err = Error::at("elsewhere", "not in the input").locate(input);
+        assert_eq!(err.span, None);
+        assert_eq!(err.describe("day_7 input"), "day_7 input: not in the input");
+
+        let line = &input[6..11];
+        let err = number::<usize>(line).unwrap_err().locate(input);
+        assert_eq!(err.to_string(), "2:1: invalid number '.....'");
+    }
+}
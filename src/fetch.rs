@@ -0,0 +1,150 @@
+use std::{io::Write, path::Path};
+
+use crate::{cli::FetchArgs, http::Request, input::Manifest, server::Server};
+
+/// What happened to the input of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    /// The input was already there, so it was not downloaded again.
+    Cached,
+    /// The input was already there, but does not match its recorded checksum.
+    Corrupted,
+}
+
+/// Downloads the input of `day` of `event` into `dir`, unless it is already there, and records
+/// its checksum in the manifest.
+pub fn fetch(
+    server: &Server,
+    event: &str,
+    dir: &Path,
+    manifest: &mut Manifest,
+    day: u8,
+) -> Result<Fetched, String> {
+    let file_name = format!("day_{day}_a_input.txt");
+    let path = dir.join(&file_name);
+    match std::fs::read(&path) {
+        Ok(cached) if !manifest.verify(&file_name, &cached) => return Ok(Fetched::Corrupted),
+        Ok(cached) => {
+            if !manifest.contains(&file_name) {
+                manifest.insert(&file_name, &cached);
+            }
+            return Ok(Fetched::Cached);
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(format!("failed to read `{}`: {err}", path.display())),
+    }
+
+    let url = server.day_url(event, day, "input");
+    let response = Request {
+        url: &url,
+        headers: vec![server.cookie()],
+        form: None,
+    }
+    .send()?;
+    match response.status {
+        200..300 => {}
+        404 => return Err(format!("the input of day {day} is not available yet")),
+        400 | 500 => {
+            return Err(format!(
+                "the puzzle server rejected the request for day {day}, the session cookie may have expired"
+            ));
+        }
+        status => return Err(format!("the puzzle server responded with status {status}")),
+    }
+
+    // Writing to a temporary file first means an interrupted download never leaves behind a
+    // truncated input.
+    let failed = |err: std::io::Error| format!("failed to write `{}`: {err}", path.display());
+    std::fs::create_dir_all(dir).map_err(failed)?;
+    let partial = dir.join(format!("{file_name}.partial"));
+    std::fs::write(&partial, &response.body).map_err(failed)?;
+    std::fs::rename(&partial, &path).map_err(failed)?;
+    manifest.insert(&file_name, &response.body);
+
+    Ok(Fetched::Downloaded)
+}
+
+/// Fetches the inputs of the days selected by `args`. Returns whether all of them are usable.
+pub fn run(args: &FetchArgs, mut out: impl Write) -> Result<bool, String> {
+    let server = Server::from_env(args.base_url.as_deref())?;
+    let dir = args.inputs.dir();
+    let mut manifest = Manifest::load(&dir)?;
+    let mut ok = true;
+    for day in &args.days {
+        let result = fetch(&server, args.event.name, &dir, &mut manifest, day.number);
+        // Save after every day, so that checksums are not lost if a later one fails.
+        manifest.save()?;
+        match result {
+            Ok(Fetched::Downloaded) => writeln!(out, "Day {}: downloaded", day.number),
+            Ok(Fetched::Cached) => writeln!(out, "Day {}: cached", day.number),
+            Ok(Fetched::Corrupted) => {
+                ok = false;
+                writeln!(
+                    out,
+                    "Day {}: cached input does not match its checksum, delete it to download it again",
+                    day.number
+                )
+            }
+            Err(err) => {
+                ok = false;
+                writeln!(out, "Day {}: error: {err}", day.number)
+            }
+        }
+        .unwrap();
+    }
+
+    Ok(ok)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fetched, fetch};
+    use crate::{
+        http::stand_in,
+        input::{Inputs, Manifest},
+        server::Server,
+    };
+
+    #[test]
+    fn fetch_and_cache() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-fetch-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let input = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n";
+        let (url, stand_in) = stand_in::serve(vec![(200, input.into()), (404, Vec::new())]);
+        let server = Server {
+            base_url: format!("{url}/"),
+            session: "s3cr3t".to_string(),
+        };
+
+        let mut manifest = Manifest::load(&dir).unwrap();
+        assert_eq!(
+            fetch(&server, "2025", &dir, &mut manifest, 5),
+            Ok(Fetched::Downloaded)
+        );
+        // The second request would fail, so this must come from the cache.
+        assert_eq!(
+            fetch(&server, "2025", &dir, &mut manifest, 5),
+            Ok(Fetched::Cached)
+        );
+        assert!(fetch(&server, "2025", &dir, &mut manifest, 6).is_err());
+        manifest.save().unwrap();
+
+        let requests = stand_in.join().unwrap();
+        assert!(requests[0].starts_with("GET /2025/day/5/input HTTP/1.0\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=s3cr3t\r\n"));
+        assert_eq!(requests.len(), 2);
+
+        let mut inputs = Inputs::default();
+        inputs.set_dir(&dir);
+        assert_eq!(inputs.read(5).unwrap(), input);
+        std::fs::write(dir.join("day_5_a_input.txt"), &input[..10]).unwrap();
+        assert!(inputs.read(5).is_err());
+        let mut manifest = Manifest::load(&dir).unwrap();
+        assert_eq!(
+            fetch(&server, "2025", &dir, &mut manifest, 5),
+            Ok(Fetched::Corrupted)
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
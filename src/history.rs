@@ -0,0 +1,420 @@
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    cli::{self, Args},
+    log,
+    registry::Part,
+    runner::Outcome,
+};
+
+/// How many of the latest timings of a puzzle its baseline is taken from.
+pub const BASELINE_RUNS: usize = 5;
+
+/// The build that timings were taken with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Build {
+    /// The commit the build was made from, like `3f2a9c1e0b7d`, with `-dirty` appended if the
+    /// working tree had changes. `unknown` if it was not built in a git checkout.
+    pub commit: String,
+    /// `debug` or `release`.
+    pub profile: &'static str,
+}
+
+impl Build {
+    /// The build that is running, as recorded by the build script when it was compiled.
+    pub fn current() -> Self {
+        Self {
+            commit: env!("AOC_COMMIT").to_string(),
+            profile: if cfg!(debug_assertions) {
+                "debug"
+            } else {
+                "release"
+            },
+        }
+    }
+}
+
+/// How timings were measured. Timings are only compared to ones measured the same way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    /// Whether the timings are the medians of benchmarks, instead of single runs.
+    pub bench: bool,
+    /// Whether the heap usage was tracked, which makes allocating slower.
+    pub memory: bool,
+    /// How many puzzles were solved at the same time.
+    pub jobs: usize,
+}
+
+impl Measurement {
+    /// How the puzzles selected by `args` are measured.
+    pub fn of(args: &Args) -> Self {
+        Self {
+            bench: args.bench.is_some(),
+            memory: args.memory,
+            // There are never more puzzles solved at the same time than were selected.
+            jobs: args.jobs.min(args.selection.len()).max(1),
+        }
+    }
+
+    /// Parses the `<bench|single> <memory|no-memory> <jobs>` fields of a timing.
+    fn parse(method: &str, memory: &str, jobs: &str) -> Option<Self> {
+        Some(Self {
+            bench: match method {
+                "bench" => true,
+                "single" => false,
+                _ => return None,
+            },
+            memory: match memory {
+                "memory" => true,
+                "no-memory" => false,
+                _ => return None,
+            },
+            jobs: jobs.parse().ok()?,
+        })
+    }
+}
+
+impl Display for Measurement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            if self.bench { "bench" } else { "single" },
+            if self.memory { "memory" } else { "no-memory" },
+            self.jobs
+        )
+    }
+}
+
+/// A puzzle that got slower than its baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    /// The median of the latest timings of the puzzle.
+    pub baseline: Duration,
+    /// How much slower the puzzle was than the baseline, in percent.
+    pub slower: f64,
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:.0}% slower than the baseline of {:.2?}",
+            self.slower, self.baseline
+        )
+    }
+}
+
+/// The timing of an outcome that is worth recording: how long solving the input took, or the
+/// median parse and solve times when benchmarking. Cached answers took no time worth recording, and
+/// failed benchmarks have no times to record.
+pub fn timing(outcome: &Outcome) -> Option<Duration> {
+    if outcome.example.is_some()
+        || outcome.answer.is_none()
+        || outcome.error.is_some()
+        || outcome.cached
+    {
+        return None;
+    }
+
+    Some(match &outcome.bench {
+        Some(bench) => bench.parse.median + bench.solve.median,
+        None => outcome.elapsed,
+    })
+}
+
+/// Timings of past runs, with one
+/// `<day><part> <nanoseconds> <commit> <profile> <bench|single> <memory|no-memory> <jobs> <unix time>`
+/// entry per line.
+///
+/// Entries from before the [`Measurement`] was recorded lack its fields. They are kept, but never
+/// compared to.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    day: u8,
+    part: Part,
+    elapsed: Duration,
+    profile: String,
+    /// Not known for entries from before it was recorded.
+    measurement: Option<Measurement>,
+}
+
+impl History {
+    /// Reads the history at `path`. A missing file is treated as an empty one.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(format!("failed to read `{}`: {err}", path.display())),
+        };
+        let mut entries = Vec::new();
+        for (idx, line) in content.lines().enumerate() {
+            let invalid = || format!("{}:{}: invalid timing `{line}`", path.display(), idx + 1);
+            let fields: Vec<_> = line.split(' ').collect();
+            let (puzzle, elapsed, profile, measurement) = match fields[..] {
+                [puzzle, elapsed, _, profile, _] => (puzzle, elapsed, profile, None),
+                [puzzle, elapsed, _, profile, method, memory, jobs, _] => {
+                    let measurement =
+                        Measurement::parse(method, memory, jobs).ok_or_else(invalid)?;
+                    (puzzle, elapsed, profile, Some(measurement))
+                }
+                _ => return Err(invalid()),
+            };
+            let (day, part) = cli::parse_day_part(puzzle).ok_or_else(invalid)?;
+            entries.push(Entry {
+                day,
+                part,
+                elapsed: Duration::from_nanos(elapsed.parse().map_err(|_| invalid())?),
+                profile: profile.to_string(),
+                measurement,
+            });
+        }
+
+        log::debug!("loaded {} timings from `{}`", entries.len(), path.display());
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+
+    /// The median of the latest [`BASELINE_RUNS`] timings of a puzzle with the same profile that
+    /// were measured the same way.
+    pub fn baseline(
+        &self,
+        day: u8,
+        part: Part,
+        profile: &str,
+        measurement: &Measurement,
+    ) -> Option<Duration> {
+        let mut latest: Vec<_> = self
+            .entries
+            .iter()
+            .rev()
+            .filter(|entry| {
+                entry.day == day
+                    && entry.part == part
+                    && entry.profile == profile
+                    && entry.measurement.as_ref() == Some(measurement)
+            })
+            .map(|entry| entry.elapsed)
+            .take(BASELINE_RUNS)
+            .collect();
+        latest.sort_unstable();
+
+        latest.get(latest.len() / 2).copied()
+    }
+
+    /// Checks whether a puzzle got more than `threshold` percent slower than its baseline.
+    pub fn compare(
+        &self,
+        build: &Build,
+        measurement: &Measurement,
+        day: u8,
+        part: Part,
+        elapsed: Duration,
+        threshold: f64,
+    ) -> Option<Regression> {
+        let baseline = self.baseline(day, part, build.profile, measurement)?;
+        let slower = (elapsed.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0;
+
+        (slower > threshold).then_some(Regression { baseline, slower })
+    }
+
+    /// Appends the timings of a run to the history.
+    pub fn record(
+        &mut self,
+        build: &Build,
+        measurement: &Measurement,
+        timings: &[(u8, Part, Duration)],
+    ) -> Result<(), String> {
+        if timings.is_empty() {
+            return Ok(());
+        }
+        let time = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let failed = |err: io::Error| {
+            format!(
+                "failed to record the timings in `{}`: {err}",
+                self.path.display()
+            )
+        };
+        let mut lines = String::new();
+        for &(day, part, elapsed) in timings {
+            lines.push_str(&format!(
+                "{day}{part} {} {} {} {measurement} {time}\n",
+                elapsed.as_nanos(),
+                build.commit,
+                build.profile
+            ));
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(failed)?;
+        file.write_all(lines.as_bytes()).map_err(failed)?;
+        log::debug!(
+            "recorded {} timings of {} in `{}`",
+            timings.len(),
+            build.commit,
+            self.path.display()
+        );
+        self.entries
+            .extend(timings.iter().map(|&(day, part, elapsed)| Entry {
+                day,
+                part,
+                elapsed,
+                profile: build.profile.to_string(),
+                measurement: Some(measurement.clone()),
+            }));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Build, History, Measurement};
+    use crate::registry::Part;
+
+    #[test]
+    fn regressions() {
+        let path = std::env::temp_dir().join(format!("aoc2025-timings-{}.txt", std::process::id()));
+        std::fs::write(
+            &path,
+            "8a 900 abc debug single no-memory 1 1\n\
+             8a 1000 abc debug single no-memory 1 2\n\
+             8a 5000 abc release single no-memory 1 3\n\
+             8b 70 abc debug single no-memory 1 4\n",
+        )
+        .unwrap();
+        let build = Build {
+            commit: "def-dirty".to_string(),
+            profile: "debug",
+        };
+        let single = Measurement {
+            bench: false,
+            memory: false,
+            jobs: 1,
+        };
+        let ns = Duration::from_nanos;
+
+        let mut history = History::load(&path).unwrap();
+        assert_eq!(
+            history.baseline(8, Part::A, "debug", &single),
+            Some(ns(1000))
+        );
+        assert_eq!(
+            history.baseline(8, Part::A, "release", &single),
+            Some(ns(5000))
+        );
+        assert_eq!(history.baseline(9, Part::A, "debug", &single), None);
+        assert_eq!(
+            history.compare(&build, &single, 8, Part::A, ns(1100), 20.0),
+            None
+        );
+        let regression = history
+            .compare(&build, &single, 8, Part::A, ns(1500), 20.0)
+            .unwrap();
+        assert_eq!(regression.baseline, ns(1000));
+        assert_eq!(
+            regression.to_string(),
+            "50% slower than the baseline of 1.00µs"
+        );
+
+        history
+            .record(
+                &build,
+                &single,
+                &[(8, Part::A, ns(800)), (8, Part::A, ns(700))],
+            )
+            .unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            content
+                .lines()
+                .nth(4)
+                .unwrap()
+                .starts_with("8a 800 def-dirty debug single no-memory 1 ")
+        );
+        let reloaded = History::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            reloaded.baseline(8, Part::A, "debug", &single),
+            Some(ns(900))
+        );
+        assert_eq!(
+            history.baseline(8, Part::A, "debug", &single),
+            Some(ns(900))
+        );
+    }
+
+    #[test]
+    fn measurements() {
+        let path =
+            std::env::temp_dir().join(format!("aoc2025-measured-{}.txt", std::process::id()));
+        std::fs::write(
+            &path,
+            "8a 900 abc release 1\n\
+             8a 1000 abc release single no-memory 1 2\n\
+             8a 3000 abc release single memory 1 3\n\
+             8a 400 abc release bench no-memory 1 4\n\
+             8a 2000 abc release single no-memory 4 5\n",
+        )
+        .unwrap();
+        let history = History::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let measured = |bench, memory, jobs| {
+            history.baseline(
+                8,
+                Part::A,
+                "release",
+                &Measurement {
+                    bench,
+                    memory,
+                    jobs,
+                },
+            )
+        };
+        let ns = Duration::from_nanos;
+        assert_eq!(measured(false, false, 1), Some(ns(1000)));
+        assert_eq!(measured(false, true, 1), Some(ns(3000)));
+        assert_eq!(measured(true, false, 1), Some(ns(400)));
+        assert_eq!(measured(false, false, 4), Some(ns(2000)));
+        assert_eq!(measured(true, true, 2), None);
+    }
+
+    #[test]
+    fn invalid() {
+        let path =
+            std::env::temp_dir().join(format!("aoc2025-bad-timings-{}.txt", std::process::id()));
+        std::fs::write(
+            &path,
+            "8a 900 abc debug 1\n8a 1 abc debug single lots 1 2\n8c 1 abc debug 2\n",
+        )
+        .unwrap();
+        let err = History::load(&path).unwrap_err();
+        assert!(err.ends_with(":2: invalid timing `8a 1 abc debug single lots 1 2`"));
+        std::fs::write(&path, "8a 900 abc debug 1\n8c 1 abc debug 2\n").unwrap();
+        let err = History::load(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(err.ends_with(":2: invalid timing `8c 1 abc debug 2`"));
+    }
+}
@@ -0,0 +1,304 @@
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+use crate::log;
+
+/// Identifies the runner to the puzzle server, as it asks automated tools to do.
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("CARGO_PKG_AUTHORS"),
+    ")"
+);
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+/// A minimal HTTP client, just enough to talk to the puzzle server.
+///
+/// Plain `http://` URLs are handled with the standard library alone, which is what the tests
+/// use. The standard library has no TLS, so `https://` URLs are handed to the system's `curl`.
+#[derive(Debug, Clone, Default)]
+pub struct Request<'a> {
+    pub url: &'a str,
+    pub headers: Vec<(&'a str, String)>,
+    /// Sent as a form with `POST` if set, otherwise the request is a `GET`.
+    pub form: Option<String>,
+}
+
+impl Request<'_> {
+    pub fn send(&self) -> Result<Response, String> {
+        log::debug!("{} {}", self.method(), self.url);
+        let response = if let Some(rest) = self.url.strip_prefix("http://") {
+            self.send_plain(rest)
+        } else if self.url.starts_with("https://") {
+            self.send_curl()
+        } else {
+            Err(format!(
+                "unsupported URL `{}`, expected `http://` or `https://`",
+                self.url
+            ))
+        }?;
+        log::debug!(
+            "{} {}: status {}, {} bytes",
+            self.method(),
+            self.url,
+            response.status,
+            response.body.len()
+        );
+
+        Ok(response)
+    }
+
+    fn method(&self) -> &'static str {
+        if self.form.is_some() { "POST" } else { "GET" }
+    }
+
+    fn send_plain(&self, url: &str) -> Result<Response, String> {
+        let (authority, path) = match url.find('/') {
+            Some(idx) => url.split_at(idx),
+            None => (url, "/"),
+        };
+        let address = if authority.contains(':') {
+            authority.to_string()
+        } else {
+            format!("{authority}:80")
+        };
+        let failed = |err: std::io::Error| format!("request to `{}` failed: {err}", self.url);
+
+        let mut stream = TcpStream::connect(&address).map_err(failed)?;
+        stream.set_read_timeout(Some(TIMEOUT)).map_err(failed)?;
+        // HTTP/1.0 keeps the response simple: no keep-alive, and usually no chunked encoding.
+        let mut request = format!(
+            "{} {path} HTTP/1.0\r\nHost: {authority}\r\nUser-Agent: {USER_AGENT}\r\n",
+            self.method()
+        );
+        for (name, value) in &self.headers {
+            request.push_str(&format!("{name}: {value}\r\n"));
+        }
+        if let Some(form) = &self.form {
+            request.push_str(&format!(
+                "Content-Type: application/x-www-form-urlencoded\r\nContent-Length: {}\r\n\r\n{form}",
+                form.len()
+            ));
+        } else {
+            request.push_str("\r\n");
+        }
+        stream.write_all(request.as_bytes()).map_err(failed)?;
+        let mut response = Vec::new();
+        let _ = stream.read_to_end(&mut response).map_err(failed)?;
+
+        parse_response(&response).ok_or_else(|| format!("invalid response from `{}`", self.url))
+    }
+
+    fn send_curl(&self) -> Result<Response, String> {
+        // The session token must not show up in the process list, so everything is passed
+        // through a config file on stdin instead of as arguments.
+        let quote =
+            |value: &str| format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""));
+        let mut config = format!(
+            "url = {}\nuser-agent = {}\n",
+            quote(self.url),
+            quote(USER_AGENT)
+        );
+        for (name, value) in &self.headers {
+            config.push_str(&format!(
+                "header = {}\n",
+                quote(&format!("{name}: {value}"))
+            ));
+        }
+        if let Some(form) = &self.form {
+            config.push_str(&format!("data-binary = {}\n", quote(form)));
+        }
+
+        let mut curl = Command::new("curl")
+            .args(["--silent", "--show-error", "--config", "-"])
+            .args(["--max-time", &TIMEOUT.as_secs().to_string()])
+            .args(["--write-out", "\n%{http_code}"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| format!("failed to run curl for `https://` URLs: {err}"))?;
+        curl.stdin
+            .take()
+            .unwrap()
+            .write_all(config.as_bytes())
+            .map_err(|err| format!("failed to configure curl: {err}"))?;
+        let output = curl
+            .wait_with_output()
+            .map_err(|err| format!("failed to run curl: {err}"))?;
+        if !output.status.success() {
+            return Err(format!(
+                "request to `{}` failed: {}",
+                self.url,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        let mut body = output.stdout;
+        let newline = body.iter().rposition(|&b| b == b'\n').unwrap_or_default();
+        let status = std::str::from_utf8(&body[newline..])
+            .ok()
+            .and_then(|status| status.trim().parse().ok())
+            .ok_or_else(|| format!("invalid response from `{}`", self.url))?;
+        body.truncate(newline);
+
+        Ok(Response { status, body })
+    }
+}
+
+fn parse_response(response: &[u8]) -> Option<Response> {
+    let end = response.windows(4).position(|w| w == b"\r\n\r\n")?;
+    let head = std::str::from_utf8(&response[..end]).ok()?;
+    let body = &response[end + 4..];
+    let mut lines = head.split("\r\n");
+    let status = lines.next()?.split(' ').nth(1)?.parse().ok()?;
+    let chunked = lines.any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.eq_ignore_ascii_case("transfer-encoding") && value.trim() == "chunked"
+        })
+    });
+
+    let body = if chunked {
+        dechunk(body)?
+    } else {
+        body.to_vec()
+    };
+
+    Some(Response { status, body })
+}
+
+fn dechunk(mut chunked: &[u8]) -> Option<Vec<u8>> {
+    let mut body = Vec::new();
+    loop {
+        let line_end = chunked.windows(2).position(|w| w == b"\r\n")?;
+        let size = std::str::from_utf8(&chunked[..line_end]).ok()?;
+        let size = usize::from_str_radix(size.split(';').next()?.trim(), 16).ok()?;
+        chunked = &chunked[line_end + 2..];
+        if size == 0 {
+            return Some(body);
+        }
+        body.extend_from_slice(chunked.get(..size)?);
+        chunked = chunked.get(size + 2..)?;
+    }
+}
+
+/// Encodes a value for a `application/x-www-form-urlencoded` body.
+pub fn form_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for b in value.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(b as char);
+            }
+            b' ' => encoded.push('+'),
+            b => encoded.push_str(&format!("%{b:02X}")),
+        }
+    }
+
+    encoded
+}
+
+/// A stand-in for the puzzle server, so that tests never reach the network.
+#[cfg(test)]
+pub(crate) mod stand_in {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        thread::JoinHandle,
+    };
+
+    /// Serves the given `(status, body)` responses to one request each, in order.
+    ///
+    /// Returns the base URL of the server, and a handle that yields the requests it got.
+    pub(crate) fn serve(responses: Vec<(u16, Vec<u8>)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                requests.push(read_request(&mut stream));
+                write!(stream, "HTTP/1.0 {status} Stand-in\r\n\r\n").unwrap();
+                stream.write_all(&body).unwrap();
+            }
+
+            requests
+        });
+
+        (url, server)
+    }
+
+    fn read_request(stream: &mut impl Read) -> String {
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        loop {
+            let n = stream.read(&mut buf).unwrap();
+            request.extend_from_slice(&buf[..n]);
+            let text = String::from_utf8_lossy(&request);
+            let Some((head, body)) = text.split_once("\r\n\r\n") else {
+                continue;
+            };
+            let length = head
+                .lines()
+                .find_map(|line| line.strip_prefix("Content-Length: "))
+                .map_or(0, |length| length.parse().unwrap());
+            if body.len() >= length || n == 0 {
+                return text.into_owned();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Response, form_encode, parse_response};
+
+    #[test]
+    fn responses() {
+        assert_eq!(
+            parse_response(b"HTTP/1.0 200 OK\r\nContent-Length: 5\r\n\r\nhello"),
+            Some(Response {
+                status: 200,
+                body: b"hello".to_vec()
+            })
+        );
+        assert_eq!(
+            parse_response(
+                b"HTTP/1.1 404 Not Found\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n2\r\nde\r\n0\r\n\r\n"
+            ),
+            Some(Response {
+                status: 404,
+                body: b"abcde".to_vec()
+            })
+        );
+        assert_eq!(parse_response(b"garbage"), None);
+    }
+
+    #[test]
+    fn form() {
+        assert_eq!(form_encode("-12 a&b=c"), "-12+a%26b%3Dc");
+    }
+}
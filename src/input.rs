@@ -0,0 +1,397 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use crate::{log, sha256};
+
+/// The file next to the inputs that records their checksums.
+pub const MANIFEST: &str = "checksums.txt";
+
+/// Where the input for a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl From<&str> for Source {
+    fn from(value: &str) -> Self {
+        if value == "-" {
+            Source::Stdin
+        } else {
+            Source::Path(value.into())
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    NotFound {
+        day: u8,
+        path: PathBuf,
+    },
+    Io {
+        source: Source,
+        err: io::Error,
+    },
+    /// The input does not match the checksum recorded when it was fetched.
+    Checksum {
+        day: u8,
+        path: PathBuf,
+    },
+    Manifest(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NotFound { day, path } => write!(
+                f,
+                "input for day {day} not found, expected it at `{}`",
+                path.display()
+            ),
+            Error::Io { source, err } => write!(f, "failed to read input `{source}`: {err}"),
+            Error::Checksum { day, path } => write!(
+                f,
+                "input `{}` does not match its checksum in `{MANIFEST}`, it may have been \
+                 truncated or edited; delete it and run `fetch {day}` to download it again",
+                path.display()
+            ),
+            Error::Manifest(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Resolves and reads the inputs of the days.
+#[derive(Debug)]
+pub struct Inputs {
+    dir: PathBuf,
+    /// The event whose inputs are read, from a directory of the same name in `dir`.
+    event: Option<&'static str>,
+    overrides: HashMap<u8, Source>,
+    stdin: OnceLock<String>,
+}
+
+impl Default for Inputs {
+    fn default() -> Self {
+        Self {
+            dir: ".".into(),
+            event: None,
+            overrides: HashMap::new(),
+            stdin: OnceLock::new(),
+        }
+    }
+}
+
+impl Inputs {
+    /// The directory the inputs of the event are in.
+    pub fn dir(&self) -> PathBuf {
+        match self.event {
+            Some(event) => self.dir.join(event),
+            None => self.dir.clone(),
+        }
+    }
+
+    pub fn set_dir(&mut self, dir: impl Into<PathBuf>) {
+        self.dir = dir.into();
+    }
+
+    pub fn set_event(&mut self, event: &'static str) {
+        self.event = Some(event);
+    }
+
+    pub fn set_override(&mut self, day: u8, source: Source) {
+        let _ = self.overrides.insert(day, source);
+    }
+
+    pub fn source(&self, day: u8) -> Source {
+        match self.overrides.get(&day) {
+            Some(source) => source.clone(),
+            None => Source::Path(self.dir().join(format!("day_{day}_a_input.txt"))),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, Error> {
+        match self.source(day) {
+            Source::Path(path) => {
+                log::debug!("reading the input of day {day} from `{}`", path.display());
+                let input = std::fs::read_to_string(&path).map_err(|err| {
+                    if err.kind() == io::ErrorKind::NotFound {
+                        Error::NotFound {
+                            day,
+                            path: path.clone(),
+                        }
+                    } else {
+                        Error::Io {
+                            source: Source::Path(path.clone()),
+                            err,
+                        }
+                    }
+                })?;
+                let (dir, file_name) = split_path(&path);
+                let manifest = Manifest::load(dir).map_err(Error::Manifest)?;
+                if !manifest.verify(file_name, input.as_bytes()) {
+                    return Err(Error::Checksum { day, path });
+                }
+
+                Ok(input)
+            }
+            Source::Stdin => {
+                // Stdin can only be consumed once, so every day reading from it gets the same input.
+                if let Some(input) = self.stdin.get() {
+                    return Ok(input.clone());
+                }
+                log::debug!("reading the input of day {day} from stdin");
+                let mut input = String::new();
+                let _ = io::stdin()
+                    .lock()
+                    .read_to_string(&mut input)
+                    .map_err(|err| Error::Io {
+                        source: Source::Stdin,
+                        err,
+                    })?;
+
+                Ok(self.stdin.get_or_init(|| input).clone())
+            }
+        }
+    }
+}
+
+/// How an input is cleaned up before it is parsed, so that parsers only ever see `\n` line
+/// endings and no stray whitespace, whatever editor the input went through.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Normalize {
+    /// Remove a UTF-8 byte order mark at the start.
+    pub byte_order_mark: bool,
+    /// Turn `\r\n` line endings into `\n`.
+    pub line_endings: bool,
+    /// Remove whitespace at the end of each line.
+    pub trailing_whitespace: bool,
+    /// End the input with exactly one `\n`, removing empty lines at the end.
+    pub final_newline: bool,
+}
+
+impl Normalize {
+    /// All of the clean-ups, which suits most puzzles.
+    pub const ALL: Self = Self {
+        byte_order_mark: true,
+        line_endings: true,
+        trailing_whitespace: true,
+        final_newline: true,
+    };
+
+    /// The input as it is.
+    pub const NONE: Self = Self {
+        byte_order_mark: false,
+        line_endings: false,
+        trailing_whitespace: false,
+        final_newline: false,
+    };
+
+    pub fn apply(&self, input: &str) -> String {
+        let mut input = input;
+        if self.byte_order_mark {
+            input = input.strip_prefix('\u{feff}').unwrap_or(input);
+        }
+
+        let mut normalized = String::with_capacity(input.len() + 1);
+        for line in input.split_inclusive('\n') {
+            let (mut content, mut ending) = match line.strip_suffix('\n') {
+                Some(content) => (content, "\n"),
+                None => (line, ""),
+            };
+            if let Some(stripped) = content.strip_suffix('\r') {
+                content = stripped;
+                if !self.line_endings {
+                    ending = &line[content.len()..];
+                }
+            }
+            if self.trailing_whitespace {
+                content = content.trim_end();
+            }
+            normalized.push_str(content);
+            normalized.push_str(ending);
+        }
+        if self.final_newline {
+            normalized.truncate(normalized.trim_end_matches(['\r', '\n']).len());
+            if !normalized.is_empty() {
+                normalized.push('\n');
+            }
+        }
+
+        normalized
+    }
+}
+
+/// Splits a path into its directory and file name.
+fn split_path(path: &Path) -> (&Path, &str) {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+
+    (dir, file_name)
+}
+
+/// SHA-256 checksums of the inputs in a directory, in the format of `sha256sum`.
+///
+/// Inputs are checked against them when they are read, so that an input that was truncated or
+/// edited by accident is noticed instead of producing a wrong answer.
+#[derive(Debug)]
+pub struct Manifest {
+    path: PathBuf,
+    checksums: BTreeMap<String, String>,
+}
+
+impl Manifest {
+    /// Reads the manifest of the inputs in `dir`. A missing file is treated as an empty one.
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let path = dir.join(MANIFEST);
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(format!("failed to read `{}`: {err}", path.display())),
+        };
+        let mut checksums = BTreeMap::new();
+        for (idx, line) in content.lines().enumerate() {
+            let Some((checksum, file_name)) = line.split_once(' ') else {
+                return Err(format!(
+                    "{}:{}: expected `<checksum>  <file name>`, got `{line}`",
+                    path.display(),
+                    idx + 1
+                ));
+            };
+            // `sha256sum` marks files read in binary mode with `*`.
+            let file_name = file_name.trim_start_matches([' ', '*']);
+            let _ = checksums.insert(file_name.to_string(), checksum.to_lowercase());
+        }
+
+        Ok(Self { path, checksums })
+    }
+
+    pub fn contains(&self, file_name: &str) -> bool {
+        self.checksums.contains_key(file_name)
+    }
+
+    /// Whether `content` matches the checksum recorded for `file_name`. Files without a
+    /// checksum always match.
+    pub fn verify(&self, file_name: &str, content: &[u8]) -> bool {
+        self.checksums
+            .get(file_name)
+            .is_none_or(|checksum| *checksum == sha256::hex_digest(content))
+    }
+
+    pub fn insert(&mut self, file_name: &str, content: &[u8]) {
+        let _ = self
+            .checksums
+            .insert(file_name.to_string(), sha256::hex_digest(content));
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let content: String = self
+            .checksums
+            .iter()
+            .map(|(file_name, checksum)| format!("{checksum}  {file_name}\n"))
+            .collect();
+
+        std::fs::write(&self.path, content)
+            .map_err(|err| format!("failed to write `{}`: {err}", self.path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{Error, Inputs, Manifest, Normalize, Source};
+
+    #[test]
+    fn sources() {
+        let mut inputs = Inputs::default();
+        inputs.set_dir("inputs/alice");
+        inputs.set_override(3, "-".into());
+        inputs.set_override(4, "day4.txt".into());
+
+        assert_eq!(
+            inputs.source(1),
+            Source::Path(PathBuf::from("inputs/alice/day_1_a_input.txt"))
+        );
+        inputs.set_event("2025");
+        assert_eq!(
+            inputs.source(1),
+            Source::Path(PathBuf::from("inputs/alice/2025/day_1_a_input.txt"))
+        );
+        assert_eq!(inputs.source(3), Source::Stdin);
+        assert_eq!(inputs.source(4), Source::Path(PathBuf::from("day4.txt")));
+    }
+
+    #[test]
+    fn missing_input() {
+        let mut inputs = Inputs::default();
+        inputs.set_dir("does/not/exist");
+
+        let err = inputs.read(7).unwrap_err();
+        assert!(matches!(err, Error::NotFound { day: 7, .. }));
+        assert_eq!(
+            err.to_string(),
+            "input for day 7 not found, expected it at `does/not/exist/day_7_a_input.txt`"
+        );
+    }
+
+    #[test]
+    fn checksums() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-manifest-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n";
+        std::fs::write(dir.join("day_6_a_input.txt"), input).unwrap();
+        let mut manifest = Manifest::load(&dir).unwrap();
+        manifest.insert("day_6_a_input.txt", input.as_bytes());
+        manifest.save().unwrap();
+        let mut inputs = Inputs::default();
+        inputs.set_dir(&dir);
+        assert_eq!(inputs.read(6).unwrap(), input);
+
+        std::fs::write(dir.join("day_6_a_input.txt"), &input[..40]).unwrap();
+        let err = inputs.read(6).unwrap_err();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(matches!(err, Error::Checksum { day: 6, .. }));
+    }
+
+    #[test]
+    fn normalize() {
+        let input = "\u{feff}..@@ \r\n@@.@\t\r\n\r\n.@..\r\n\n\n";
+        assert_eq!(Normalize::ALL.apply(input), "..@@\n@@.@\n\n.@..\n");
+        assert_eq!(Normalize::NONE.apply(input), input);
+        assert_eq!(Normalize::ALL.apply("1-2,3-4"), "1-2,3-4\n");
+        assert_eq!(Normalize::ALL.apply(""), "");
+
+        // Columns that end in spaces are significant for some puzzles.
+        let keep_spaces = Normalize {
+            trailing_whitespace: false,
+            ..Normalize::ALL
+        };
+        assert_eq!(
+            keep_spaces.apply("12 4 \r\n 3 56\r\n* + "),
+            "12 4 \n 3 56\n* + \n"
+        );
+    }
+}
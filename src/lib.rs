@@ -0,0 +1,59 @@
+//! Solutions for the puzzles of Advent of Code and similar events, and the runner around them.
+//!
+//! Every day implements [`Solution`](solution::Solution), which splits parsing the input from
+//! solving the puzzles:
+//!
+//! ```
+//! use aoc2025::{solution::Solution, y2025::day11::Day11};
+//!
+//! let day = Day11::new();
+//! let graph = day.parse("you: a b\na: out\nb: out\n")?;
+//! assert_eq!(day.part_a(&graph)?, 2.into());
+//! assert_eq!(day.part_a(&graph)?.to_string(), "2");
+//! # Ok::<(), aoc2025::error::Error>(())
+//! ```
+//!
+//! The days are grouped by event, like [`y2025`], and listed in the [`registry`], which is what the
+//! `aoc2025` binary runs. The building blocks they share, like the [`Grid`](common::grid::Grid)
+//! that Day 4 parses its input into, are in [`common`].
+
+pub mod answer;
+pub(crate) mod app;
+pub(crate) mod bench;
+pub(crate) mod bisect;
+pub(crate) mod cache;
+pub(crate) mod cancel;
+pub(crate) mod check;
+pub(crate) mod cli;
+pub mod common;
+pub mod error;
+pub(crate) mod fetch;
+pub(crate) mod history;
+pub(crate) mod http;
+pub(crate) mod input;
+pub(crate) mod log;
+pub(crate) mod memory;
+pub(crate) mod output;
+pub(crate) mod panic;
+pub mod registry;
+pub(crate) mod repl;
+pub(crate) mod runner;
+pub(crate) mod scaffold;
+pub(crate) mod server;
+pub(crate) mod sha256;
+pub mod solution;
+pub(crate) mod submit;
+pub(crate) mod watch;
+pub mod y2025;
+
+/// Runs the `aoc2025` command line, which is all its binary does.
+#[doc(hidden)]
+pub fn main() -> std::process::ExitCode {
+    let code = app::run();
+    log::flush();
+    code
+}
+
+/// The allocator the binary counts heap usage with.
+#[doc(hidden)]
+pub use memory::Counting;
@@ -0,0 +1,281 @@
+//! A small logging facility, for diagnostics that are only wanted while looking into something.
+//!
+//! Messages are logged with the `error!`, `warning!`, `info!`, `debug!` and `trace!` macros,
+//! and go to stderr if the filter in `AOC_LOG` lets them through. The filter is a default level
+//! and levels for single targets, like `warn,day10=trace`. A target is any part of the module path
+//! of the message, so `day10` is day 10, `y2025` all days of the event, and `runner` the runner.
+//!
+//! Messages are buffered, so [`flush`] has to be called before the process exits.
+
+use std::{
+    fmt::{Arguments, Display},
+    io::{BufWriter, Stderr, Write},
+    str::FromStr,
+    sync::{Mutex, OnceLock},
+};
+
+/// The environment variable the filter is read from.
+pub const ENV: &str = "AOC_LOG";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!("unknown log level `{s}`")),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Level::Error => write!(f, "ERROR"),
+            Level::Warn => write!(f, "WARN"),
+            Level::Info => write!(f, "INFO"),
+            Level::Debug => write!(f, "DEBUG"),
+            Level::Trace => write!(f, "TRACE"),
+        }
+    }
+}
+
+/// Which messages get logged: up to a default level, unless a more specific target says otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    default: Option<Level>,
+    /// The levels of targets, as `(target, level)`. `None` turns a target off.
+    targets: Vec<(String, Option<Level>)>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self {
+            default: Some(Level::Warn),
+            targets: Vec::new(),
+        }
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let level = |value: &str| match value {
+            "off" => Ok(None),
+            value => value.parse().map(Some),
+        };
+        let mut filter = Filter::default();
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, value)) => {
+                    filter.targets.push((target.to_string(), level(value)?));
+                }
+                None => filter.default = level(directive)?,
+            }
+        }
+
+        Ok(filter)
+    }
+}
+
+impl Filter {
+    /// Whether a message at `level` from the module at `path` gets logged.
+    ///
+    /// The most specific target wins: the one naming the deepest module in the path, then the
+    /// longest one, then the last one.
+    pub fn enabled(&self, path: &str, level: Level) -> bool {
+        let max = self
+            .targets
+            .iter()
+            .filter_map(|(target, level)| {
+                let end = find(path, target)?;
+                Some(((end, target.split("::").count()), level))
+            })
+            .max_by_key(|&(specificity, _)| specificity)
+            .map_or(self.default, |(_, level)| *level);
+
+        max.is_some_and(|max| level <= max)
+    }
+}
+
+/// Finds `target` as a run of whole segments of the module path `path`, returning the index of
+/// the segment after the last match.
+fn find(path: &str, target: &str) -> Option<usize> {
+    let len = target.split("::").count();
+    path.split("::")
+        .collect::<Vec<_>>()
+        .windows(len)
+        .rposition(|segments| segments.iter().copied().eq(target.split("::")))
+        .map(|idx| idx + len)
+}
+
+struct Logger {
+    filter: Filter,
+    out: Mutex<BufWriter<Stderr>>,
+}
+
+fn logger() -> &'static Logger {
+    static LOGGER: OnceLock<Logger> = OnceLock::new();
+    LOGGER.get_or_init(|| {
+        let (filter, err) = match std::env::var(ENV).map(|value| value.parse::<Filter>()) {
+            Ok(Ok(filter)) => (filter, None),
+            Ok(Err(err)) => (Filter::default(), Some(err)),
+            Err(_) => (Filter::default(), None),
+        };
+        if let Some(err) = err {
+            eprintln!("warning: ignoring `{ENV}`: {err}");
+        }
+
+        Logger {
+            filter,
+            out: Mutex::new(BufWriter::new(std::io::stderr())),
+        }
+    })
+}
+
+/// Whether a message at `level` from the module at `path` gets logged.
+pub fn enabled(path: &str, level: Level) -> bool {
+    logger().filter.enabled(path, level)
+}
+
+/// Logs a message. Use the macros instead, which skip formatting messages nobody wants.
+pub fn write(path: &str, level: Level, message: Arguments) {
+    // Each message is written in one go, so that messages from different threads don't mix.
+    let record = format(path, level, std::thread::current().name(), message);
+    let mut out = logger().out.lock().unwrap_or_else(|err| err.into_inner());
+    let _ = out.write_all(record.as_bytes());
+    if level == Level::Error {
+        let _ = out.flush();
+    }
+}
+
+/// Writes out the buffered messages.
+pub fn flush() {
+    let mut out = logger().out.lock().unwrap_or_else(|err| err.into_inner());
+    let _ = out.flush();
+}
+
+/// Formats a message like `DEBUG y2025::day10 [day 10b]: found a solution`, where the thread is
+/// only named if it has a name of its own.
+fn format(path: &str, level: Level, thread: Option<&str>, message: Arguments) -> String {
+    let target = path
+        .strip_prefix(concat!(env!("CARGO_CRATE_NAME"), "::"))
+        .unwrap_or(path);
+    match thread.filter(|&name| name != "main") {
+        Some(thread) => format!("{level} {target} [{thread}]: {message}\n"),
+        None => format!("{level} {target}: {message}\n"),
+    }
+}
+
+macro_rules! log {
+    (target: $target:expr, $level:expr, $($arg:tt)+) => {{
+        let target: &str = &$target;
+        if $crate::log::enabled(target, $level) {
+            $crate::log::write(target, $level, format_args!($($arg)+));
+        }
+    }};
+    ($level:expr, $($arg:tt)+) => {
+        $crate::log::log!(target: module_path!(), $level, $($arg)+)
+    };
+}
+
+macro_rules! error {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::log::log!(target: $target, $crate::log::Level::Error, $($arg)+)
+    };
+    ($($arg:tt)+) => { $crate::log::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+macro_rules! warning {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::log::log!(target: $target, $crate::log::Level::Warn, $($arg)+)
+    };
+    ($($arg:tt)+) => { $crate::log::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+macro_rules! info {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::log::log!(target: $target, $crate::log::Level::Info, $($arg)+)
+    };
+    ($($arg:tt)+) => { $crate::log::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+macro_rules! debug {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::log::log!(target: $target, $crate::log::Level::Debug, $($arg)+)
+    };
+    ($($arg:tt)+) => { $crate::log::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+macro_rules! trace {
+    (target: $target:expr, $($arg:tt)+) => {
+        $crate::log::log!(target: $target, $crate::log::Level::Trace, $($arg)+)
+    };
+    ($($arg:tt)+) => { $crate::log::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+pub(crate) use {debug, error, info, log, trace, warning};
+
+#[cfg(test)]
+mod tests {
+    use super::{Filter, Level, format};
+
+    #[test]
+    fn filter() {
+        let filter: Filter = "info, y2025=warn, day10=trace, runner=off".parse().unwrap();
+        assert!(filter.enabled("aoc2025::fetch", Level::Info));
+        assert!(!filter.enabled("aoc2025::fetch", Level::Debug));
+        assert!(!filter.enabled("aoc2025::y2025::day8", Level::Info));
+        assert!(filter.enabled("aoc2025::y2025::day10", Level::Trace));
+        assert!(!filter.enabled("aoc2025::y2025::day1", Level::Trace));
+        assert!(!filter.enabled("aoc2025::runner", Level::Error));
+
+        let filter: Filter = "y2025::day10=debug".parse().unwrap();
+        assert!(filter.enabled("aoc2025::y2025::day10", Level::Debug));
+        assert!(filter.enabled("aoc2025::runner", Level::Warn));
+        assert!(!filter.enabled("aoc2025::runner", Level::Info));
+
+        assert_eq!("".parse(), Ok(Filter::default()));
+        assert!("day10=loud".parse::<Filter>().is_err());
+    }
+
+    #[test]
+    fn records() {
+        assert_eq!(
+            format(
+                "aoc2025::y2025::day10",
+                Level::Debug,
+                Some("day 10b"),
+                format_args!("found {}", 3)
+            ),
+            "DEBUG y2025::day10 [day 10b]: found 3\n"
+        );
+        assert_eq!(
+            format(
+                "aoc2025::runner",
+                Level::Warn,
+                Some("main"),
+                format_args!("x")
+            ),
+            "WARN runner: x\n"
+        );
+        assert_eq!(
+            format("y2025::day7", Level::Info, None, format_args!("x")),
+            "INFO y2025::day7: x\n"
+        );
+    }
+}
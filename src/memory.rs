@@ -0,0 +1,200 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::RefCell,
+    fmt::Display,
+    sync::{
+        Arc,
+        atomic::{AtomicIsize, AtomicUsize, Ordering},
+    },
+};
+
+/// A global allocator that counts the allocations made inside of `track`.
+///
+/// The counters are kept per tracked closure, and shared with the threads it hands its `Tracker`
+/// to, so puzzles solved at the same time do not skew each other. Outside of `track`, it only
+/// adds a thread-local lookup to [`System`].
+#[derive(Debug)]
+pub struct Counting;
+
+/// The heap usage of a tracked closure.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// The most memory that was allocated at any one time, in bytes.
+    pub peak: usize,
+    /// All memory that was allocated, including memory that was freed again, in bytes.
+    pub total: usize,
+    pub allocations: usize,
+}
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "peak {}, {} allocated in {} allocations",
+            Bytes(self.peak),
+            Bytes(self.total),
+            self.allocations
+        )
+    }
+}
+
+struct Bytes(usize);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+
+        write!(f, "{value:.2} {}", UNITS[unit])
+    }
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    /// Memory allocated since tracking started. Freeing memory that was allocated before can
+    /// make this negative.
+    current: AtomicIsize,
+    peak: AtomicIsize,
+    total: AtomicUsize,
+    allocations: AtomicUsize,
+}
+
+/// The counters allocations on a thread are recorded in, to pass on to threads spawned by a
+/// solver so that their allocations are counted too.
+#[derive(Debug, Clone, Default)]
+pub struct Tracker(Option<Arc<Counters>>);
+
+thread_local! {
+    static CURRENT: RefCell<Tracker> = const { RefCell::new(Tracker(None)) };
+}
+
+fn record(allocated: usize, freed: usize) {
+    // The thread-local may already be gone while a thread shuts down, or be borrowed by `with`.
+    let _ = CURRENT.try_with(|tracker| {
+        if let Ok(tracker) = tracker.try_borrow()
+            && let Some(c) = &tracker.0
+        {
+            let change = allocated as isize - freed as isize;
+            let current = c.current.fetch_add(change, Ordering::Relaxed) + change;
+            let _ = c.peak.fetch_max(current, Ordering::Relaxed);
+            let _ = c.total.fetch_add(allocated, Ordering::Relaxed);
+            let _ = c
+                .allocations
+                .fetch_add(usize::from(allocated > 0), Ordering::Relaxed);
+        }
+    });
+}
+
+/// Runs `f` with `tracker` counting the allocations of the current thread.
+pub fn with<T>(tracker: Tracker, f: impl FnOnce() -> T) -> T {
+    let previous = CURRENT.replace(tracker);
+    let result = f();
+    // Dropping the tracker can free its counters, which must not happen while it is borrowed.
+    let tracker = CURRENT.replace(previous);
+    drop(tracker);
+
+    result
+}
+
+/// The tracker of the current thread, to pass on to threads spawned by a solver.
+pub fn current() -> Tracker {
+    CURRENT.with_borrow(Tracker::clone)
+}
+
+/// Runs `f`, counting the heap memory it allocates on the current thread, and on the threads it
+/// passes the [`current`] tracker to.
+///
+/// Only counts anything if [`Counting`] is the global allocator. Allocations inside of a nested
+/// call are only counted by that call.
+pub fn track<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let counters = Arc::new(Counters::default());
+    let result = with(Tracker(Some(counters.clone())), f);
+
+    let usage = Usage {
+        peak: counters.peak.load(Ordering::Relaxed) as usize,
+        total: counters.total.load(Ordering::Relaxed),
+        allocations: counters.allocations.load(Ordering::Relaxed),
+    };
+
+    (result, usage)
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bytes, Counting, current, track, with};
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    #[test]
+    fn bytes() {
+        assert_eq!(Bytes(1000).to_string(), "1000 B");
+        assert_eq!(Bytes(1536).to_string(), "1.50 KiB");
+        assert_eq!(Bytes(3 << 20).to_string(), "3.00 MiB");
+    }
+
+    #[test]
+    fn track_allocations() {
+        let (_, usage) = track(|| {
+            for _ in 0..2 {
+                let _ = std::hint::black_box(vec![0u8; 1024]);
+            }
+        });
+        assert_eq!(usage.peak, 1024);
+        assert_eq!(usage.total, 2048);
+        assert_eq!(usage.allocations, 2);
+
+        let (_, usage) = track(|| {
+            let tracker = current();
+            let spawned = std::thread::spawn(move || {
+                with(tracker, || std::hint::black_box(vec![0u8; 1 << 20]).len())
+            });
+            assert_eq!(spawned.join().unwrap(), 1 << 20);
+        });
+        assert!(usage.peak >= 1 << 20, "{usage:?}");
+
+        let outside = vec![0u8; 4096];
+        let (_, usage) = track(|| drop(outside));
+        assert_eq!(usage.peak, 0);
+        assert_eq!(usage.allocations, 0);
+    }
+}
@@ -0,0 +1,522 @@
+use std::{fmt::Write as _, io::Write, str::FromStr};
+
+use crate::{
+    answer::Answer,
+    bench::Stats,
+    check::Status,
+    runner::{Bench, Outcome},
+};
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format `{s}`, expected `text`, `json` or `csv`"
+            )),
+        }
+    }
+}
+
+const CSV_HEADER: &str = "day,part,answer,elapsed_ns,error,check,expected,\
+parse_min_ns,parse_median_ns,parse_p95_ns,parse_max_ns,\
+solve_min_ns,solve_median_ns,solve_p95_ns,solve_max_ns,example,\
+peak_bytes,allocated_bytes,allocations,timed_out,baseline_ns,slower_percent,cached,panic";
+
+/// Writes outcomes as they come in, in the chosen format.
+#[derive(Debug)]
+pub struct Printer<W: Write> {
+    format: Format,
+    out: W,
+    count: usize,
+    statuses: Vec<Status>,
+    /// The puzzles that could not be solved and why, like `11b panicked`.
+    failed: Vec<String>,
+}
+
+impl<W: Write> Printer<W> {
+    pub fn new(format: Format, out: W) -> Self {
+        Self {
+            format,
+            out,
+            count: 0,
+            statuses: Vec::new(),
+            failed: Vec::new(),
+        }
+    }
+
+    pub fn print(&mut self, outcome: &Outcome) {
+        if let Some(status) = &outcome.check {
+            self.statuses.push(status.clone());
+        }
+        let failure = if outcome.panic.is_some() {
+            Some("panicked")
+        } else if outcome.timed_out {
+            Some("timed out")
+        } else if outcome.error.is_some() {
+            Some("failed")
+        } else {
+            None
+        };
+        if let Some(failure) = failure {
+            self.failed
+                .push(format!("{}{} {failure}", outcome.day.number, outcome.part));
+        }
+        match self.format {
+            Format::Text => self.print_text(outcome),
+            Format::Json => {
+                let separator = if self.count == 0 { "[" } else { "," };
+                writeln!(self.out, "{separator}").unwrap();
+                write!(self.out, "  {}", json(outcome)).unwrap();
+            }
+            Format::Csv => {
+                if self.count == 0 {
+                    writeln!(self.out, "{CSV_HEADER}").unwrap();
+                }
+                writeln!(self.out, "{}", csv(outcome)).unwrap();
+            }
+        }
+        self.count += 1;
+    }
+
+    pub fn finish(mut self) -> W {
+        match self.format {
+            Format::Text => {
+                if !self.statuses.is_empty() {
+                    let count =
+                        |f: fn(&Status) -> bool| self.statuses.iter().filter(|s| f(s)).count();
+                    writeln!(
+                        self.out,
+                        "=== Checked {} puzzles: {} passed, {} failed, {} unknown ===",
+                        self.statuses.len(),
+                        count(|s| *s == Status::Pass),
+                        count(|s| matches!(s, Status::Fail { .. })),
+                        count(|s| *s == Status::Unknown),
+                    )
+                    .unwrap();
+                }
+                if !self.failed.is_empty() {
+                    writeln!(
+                        self.out,
+                        "=== FAILED: {} of {} puzzles could not be solved ({}) ===",
+                        self.failed.len(),
+                        self.count,
+                        self.failed.join(", ")
+                    )
+                    .unwrap();
+                }
+            }
+            Format::Json if self.count == 0 => writeln!(self.out, "[]").unwrap(),
+            Format::Json => writeln!(self.out, "\n]").unwrap(),
+            Format::Csv if self.count == 0 => writeln!(self.out, "{CSV_HEADER}").unwrap(),
+            Format::Csv => {}
+        }
+        self.out.flush().unwrap();
+
+        self.out
+    }
+
+    fn print_text(&mut self, outcome: &Outcome) {
+        match outcome.example {
+            Some(example) => writeln!(
+                self.out,
+                "=== Day {} - {} (example {example}) ===",
+                outcome.day.number,
+                outcome.part.name()
+            ),
+            None => writeln!(
+                self.out,
+                "=== Day {} - {} ===",
+                outcome.day.number,
+                outcome.part.name()
+            ),
+        }
+        .unwrap();
+        if let Some(err) = &outcome.error {
+            writeln!(self.out, "Solution: FAILED ({err})").unwrap();
+        }
+        if let Some(answer) = &outcome.answer {
+            let cached = if outcome.cached { " (cached)" } else { "" };
+            match &answer.unit {
+                Some(unit) => writeln!(self.out, "Solution: `{answer}` {unit}{cached}"),
+                None => writeln!(self.out, "Solution: `{answer}`{cached}"),
+            }
+            .unwrap();
+            if let Some(picture) = &answer.picture {
+                for line in picture.lines() {
+                    writeln!(self.out, "    {line}").unwrap();
+                }
+            }
+        }
+        if outcome.timed_out {
+            writeln!(
+                self.out,
+                "Solution: TIMEOUT (after {:.2?})",
+                outcome.elapsed
+            )
+            .unwrap();
+        }
+        if let Some(panic) = &outcome.panic {
+            writeln!(self.out, "Solution: FAILED ({panic})").unwrap();
+        }
+        if let Some(status) = &outcome.check {
+            writeln!(self.out, "Check: {status}").unwrap();
+        }
+        if let Some(regression) = &outcome.regression {
+            writeln!(self.out, "Regression: {regression}").unwrap();
+        }
+        if let Some(bench) = &outcome.bench {
+            writeln!(self.out, "Parse: {}", bench.parse).unwrap();
+            writeln!(self.out, "Solve: {}", bench.solve).unwrap();
+        }
+        if let Some(memory) = &outcome.memory {
+            writeln!(self.out, "Memory: {memory}").unwrap();
+        }
+    }
+}
+
+fn json(outcome: &Outcome) -> String {
+    let mut out = String::new();
+    write!(
+        out,
+        r#"{{"day": {}, "part": "{}", "answer": {}, "elapsed_ns": {}, "error": {}"#,
+        outcome.day.number,
+        outcome.part,
+        json_string(outcome.answer.as_ref().map(Answer::to_string).as_deref()),
+        outcome.elapsed.as_nanos(),
+        json_string(outcome.error.as_deref()),
+    )
+    .unwrap();
+    if let Some(answer) = &outcome.answer {
+        if let Some(unit) = answer.unit.as_deref() {
+            write!(out, r#", "unit": {}"#, json_string(Some(unit))).unwrap();
+        }
+        if let Some(picture) = &answer.picture {
+            write!(out, r#", "picture": {}"#, json_string(Some(picture))).unwrap();
+        }
+    }
+    if let Some(example) = outcome.example {
+        write!(out, r#", "example": {example}"#).unwrap();
+    }
+    if outcome.timed_out {
+        write!(out, r#", "timed_out": true"#).unwrap();
+    }
+    if outcome.cached {
+        write!(out, r#", "cached": true"#).unwrap();
+    }
+    if let Some(panic) = &outcome.panic {
+        write!(out, r#", "panic": {}"#, json_string(Some(panic))).unwrap();
+    }
+    if let Some(status) = &outcome.check {
+        let (check, expected) = status_fields(status);
+        write!(
+            out,
+            r#", "check": "{check}", "expected": {}"#,
+            json_string(expected)
+        )
+        .unwrap();
+    }
+    if let Some(regression) = &outcome.regression {
+        write!(
+            out,
+            r#", "regression": {{"baseline_ns": {}, "slower_percent": {:.1}}}"#,
+            regression.baseline.as_nanos(),
+            regression.slower
+        )
+        .unwrap();
+    }
+    if let Some(Bench { parse, solve }) = &outcome.bench {
+        write!(
+            out,
+            r#", "bench": {{"parse": {}, "solve": {}}}"#,
+            json_stats(parse),
+            json_stats(solve)
+        )
+        .unwrap();
+    }
+    if let Some(memory) = &outcome.memory {
+        write!(
+            out,
+            r#", "memory": {{"peak_bytes": {}, "allocated_bytes": {}, "allocations": {}}}"#,
+            memory.peak, memory.total, memory.allocations
+        )
+        .unwrap();
+    }
+    out.push('}');
+
+    out
+}
+
+fn json_stats(stats: &Stats) -> String {
+    format!(
+        r#"{{"min_ns": {}, "median_ns": {}, "p95_ns": {}, "max_ns": {}, "iterations": {}}}"#,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.p95.as_nanos(),
+        stats.max.as_nanos(),
+        stats.iterations
+    )
+}
+
+fn json_string(value: Option<&str>) -> String {
+    let Some(value) = value else {
+        return "null".to_string();
+    };
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+
+    out
+}
+
+fn csv(outcome: &Outcome) -> String {
+    let (check, expected) = match &outcome.check {
+        Some(status) => status_fields(status),
+        None => ("", None),
+    };
+    let mut fields = vec![
+        outcome.day.number.to_string(),
+        outcome.part.to_string(),
+        csv_field(
+            &outcome
+                .answer
+                .as_ref()
+                .map(Answer::to_string)
+                .unwrap_or_default(),
+        ),
+        outcome.elapsed.as_nanos().to_string(),
+        csv_field(outcome.error.as_deref().unwrap_or_default()),
+        check.to_string(),
+        csv_field(expected.unwrap_or_default()),
+    ];
+    let parse = outcome.bench.as_ref().map(|b| &b.parse);
+    let solve = outcome.bench.as_ref().map(|b| &b.solve);
+    for stats in [parse, solve] {
+        match stats {
+            Some(stats) => fields.extend(
+                [stats.min, stats.median, stats.p95, stats.max].map(|d| d.as_nanos().to_string()),
+            ),
+            None => fields.extend(std::iter::repeat_n(String::new(), 4)),
+        }
+    }
+    fields.push(outcome.example.map(|n| n.to_string()).unwrap_or_default());
+    match &outcome.memory {
+        Some(memory) => {
+            fields.extend([memory.peak, memory.total, memory.allocations].map(|n| n.to_string()))
+        }
+        None => fields.extend(std::iter::repeat_n(String::new(), 3)),
+    }
+    fields.push(if outcome.timed_out { "true" } else { "" }.to_string());
+    match &outcome.regression {
+        Some(regression) => fields.extend([
+            regression.baseline.as_nanos().to_string(),
+            format!("{:.1}", regression.slower),
+        ]),
+        None => fields.extend(std::iter::repeat_n(String::new(), 2)),
+    }
+    fields.push(if outcome.cached { "true" } else { "" }.to_string());
+    fields.push(csv_field(outcome.panic.as_deref().unwrap_or_default()));
+
+    fields.join(",")
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn status_fields(status: &Status) -> (&'static str, Option<&str>) {
+    match status {
+        Status::Pass => ("pass", None),
+        Status::Fail { expected } => ("fail", Some(expected)),
+        Status::Unknown => ("unknown", None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Format, Printer};
+    use crate::{
+        answer::Answer, check::Status, history::Regression, memory::Usage, registry,
+        registry::Part, runner::Outcome,
+    };
+
+    fn outcomes() -> [Outcome; 3] {
+        [
+            Outcome {
+                day: registry::default_event().find(1).unwrap(),
+                part: Part::B,
+                example: Some(1),
+                answer: Some(Answer::from(6_u64).with_unit("presses")),
+                cached: true,
+                elapsed: Duration::from_micros(12),
+                error: None,
+                timed_out: false,
+                panic: None,
+                check: Some(Status::Fail {
+                    expected: "7".to_string(),
+                }),
+                regression: Some(Regression {
+                    baseline: Duration::from_micros(8),
+                    slower: 50.0,
+                }),
+                bench: None,
+                memory: Some(Usage {
+                    peak: 2048,
+                    total: 4096,
+                    allocations: 3,
+                }),
+            },
+            Outcome {
+                day: registry::default_event().find(3).unwrap(),
+                part: Part::A,
+                example: None,
+                answer: None,
+                cached: false,
+                elapsed: Duration::ZERO,
+                error: Some("input for day 3 not found, expected it at `\"x\",y`".to_string()),
+                timed_out: false,
+                panic: None,
+                check: None,
+                regression: None,
+                bench: None,
+                memory: None,
+            },
+            Outcome {
+                day: registry::default_event().find(11).unwrap(),
+                part: Part::A,
+                example: None,
+                answer: None,
+                cached: false,
+                elapsed: Duration::from_micros(3),
+                error: None,
+                timed_out: false,
+                panic: Some("panicked at src/y2025/day11.rs:9:5: no root".to_string()),
+                check: None,
+                regression: None,
+                bench: None,
+                memory: None,
+            },
+        ]
+    }
+
+    fn print(format: Format) -> String {
+        let mut printer = Printer::new(format, Vec::new());
+        for outcome in &outcomes() {
+            printer.print(outcome);
+        }
+
+        String::from_utf8(printer.finish()).unwrap()
+    }
+
+    #[test]
+    fn text() {
+        let text = print(Format::Text);
+        let lines: Vec<_> = text.lines().collect();
+
+        assert_eq!(
+            lines[..3],
+            [
+                "=== Day 1 - Second puzzle (example 1) ===",
+                "Solution: `6` presses (cached)",
+                "Check: FAIL (expected `7`)",
+            ]
+        );
+        assert_eq!(
+            lines[lines.len() - 6..],
+            [
+                "=== Day 3 - First puzzle ===",
+                "Solution: FAILED (input for day 3 not found, expected it at `\"x\",y`)",
+                "=== Day 11 - First puzzle ===",
+                "Solution: FAILED (panicked at src/y2025/day11.rs:9:5: no root)",
+                "=== Checked 1 puzzles: 0 passed, 1 failed, 0 unknown ===",
+                "=== FAILED: 2 of 3 puzzles could not be solved (3a failed, 11a panicked) ===",
+            ]
+        );
+
+        let [mut timed_out, ..] = outcomes();
+        timed_out.answer = None;
+        timed_out.check = None;
+        timed_out.timed_out = true;
+        let mut printer = Printer::new(Format::Text, Vec::new());
+        printer.print(&timed_out);
+        let text = String::from_utf8(printer.finish()).unwrap();
+        assert!(text.ends_with(
+            "Solution: TIMEOUT (after 12.00µs)\n\
+             Regression: 50% slower than the baseline of 8.00µs\n\
+             Memory: peak 2.00 KiB, 4.00 KiB allocated in 3 allocations\n\
+             === FAILED: 1 of 1 puzzles could not be solved (1b timed out) ===\n"
+        ));
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            print(Format::Json),
+            r#"[
+  {"day": 1, "part": "b", "answer": "6", "elapsed_ns": 12000, "error": null, "unit": "presses", "example": 1, "cached": true, "check": "fail", "expected": "7", "regression": {"baseline_ns": 8000, "slower_percent": 50.0}, "memory": {"peak_bytes": 2048, "allocated_bytes": 4096, "allocations": 3}},
+  {"day": 3, "part": "a", "answer": null, "elapsed_ns": 0, "error": "input for day 3 not found, expected it at `\"x\",y`"},
+  {"day": 11, "part": "a", "answer": null, "elapsed_ns": 3000, "error": null, "panic": "panicked at src/y2025/day11.rs:9:5: no root"}
+]
+"#
+        );
+        assert_eq!(
+            String::from_utf8(Printer::new(Format::Json, Vec::new()).finish()).unwrap(),
+            "[]\n"
+        );
+    }
+
+    #[test]
+    fn csv() {
+        let csv = print(Format::Csv);
+        let mut lines = csv.lines();
+
+        assert!(
+            lines
+                .next()
+                .unwrap()
+                .starts_with("day,part,answer,elapsed_ns,error")
+        );
+        assert_eq!(
+            lines.next().unwrap(),
+            "1,b,6,12000,,fail,7,,,,,,,,,1,2048,4096,3,,8000,50.0,true,"
+        );
+        assert_eq!(
+            lines.next().unwrap(),
+            "3,a,,0,\"input for day 3 not found, expected it at `\"\"x\"\",y`\",,,,,,,,,,,,,,,,,,,"
+        );
+        assert_eq!(
+            lines.next().unwrap(),
+            "11,a,,3000,,,,,,,,,,,,,,,,,,,,panicked at src/y2025/day11.rs:9:5: no root"
+        );
+        assert_eq!(lines.next(), None);
+    }
+}
@@ -0,0 +1,98 @@
+use std::{
+    any::Any,
+    cell::Cell,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+/// A caught panic, and where it happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// Where the panic happened, like `src/y2025/day11.rs:57:40`.
+    pub location: Option<String>,
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {location}: {}", self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    /// Whether the current thread is inside of [`catch`], whose panics are reported by it.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// The last panic on the current thread inside of [`catch`].
+    static CAUGHT: Cell<Option<Panic>> = const { Cell::new(None) };
+}
+
+/// Runs `f`, catching any panic instead of letting it unwind further.
+///
+/// Caught panics are not printed. Panics on threads spawned by `f` are, and only reach `f` if the
+/// thread is joined, after which they are caught like any other.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Panic> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        // The location of a panic is only known to the panic hook, not to whoever catches it.
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                CAUGHT.set(Some(Panic {
+                    message: message(info.payload()),
+                    location: info.location().map(ToString::to_string),
+                }));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+
+    let catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(catching);
+
+    result.map_err(|payload| {
+        CAUGHT.take().unwrap_or_else(|| Panic {
+            message: message(payload.as_ref()),
+            location: None,
+        })
+    })
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::catch;
+
+    #[test]
+    fn catch_panics() {
+        assert_eq!(catch(|| 3), Ok(3));
+
+        let line = line!() + 1;
+        let panic = catch(|| "".parse::<u8>().expect("no root")).unwrap_err();
+        assert_eq!(panic.message, "no root: ParseIntError { kind: Empty }");
+        let location = format!("src/panic.rs:{line}:");
+        assert!(panic.location.as_ref().unwrap().starts_with(&location));
+        assert!(
+            panic
+                .to_string()
+                .starts_with(&format!("panicked at {location}"))
+        );
+
+        let panic = catch(|| std::panic::panic_any(7)).unwrap_err();
+        assert_eq!(panic.message, "Box<dyn Any>");
+    }
+}
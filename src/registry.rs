@@ -0,0 +1,106 @@
+use std::fmt::Display;
+
+use crate::{solution::Puzzle, y2025};
+
+/// One of the two puzzles of a day.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    pub fn name(self) -> &'static str {
+        match self {
+            Part::A => "First puzzle",
+            Part::B => "Second puzzle",
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::A => write!(f, "a"),
+            Part::B => write!(f, "b"),
+        }
+    }
+}
+
+/// A solved day, with the solution of its puzzles.
+pub struct Day {
+    pub number: u8,
+    pub module: &'static str,
+    pub puzzle: &'static dyn Puzzle,
+}
+
+impl std::fmt::Debug for Day {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Day")
+            .field("number", &self.number)
+            .field("module", &self.module)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Day {
+    pub fn has_part(&self, part: Part) -> bool {
+        self.puzzle.has_part(part)
+    }
+
+    pub fn parts(&self) -> impl Iterator<Item = Part> {
+        [Part::A, Part::B]
+            .into_iter()
+            .filter(|&part| self.has_part(part))
+    }
+}
+
+/// A puzzle event, like one year of Advent of Code, and its days.
+#[derive(Debug)]
+pub struct Event {
+    /// The name the event is selected by, like `2025`. It is also the event's path on the puzzle
+    /// server, and the directory of its inputs.
+    pub name: &'static str,
+    /// The module of the event, with one module per day below it.
+    pub module: &'static str,
+    pub days: &'static [Day],
+}
+
+impl Event {
+    pub fn find(&'static self, number: u8) -> Option<&'static Day> {
+        self.days.iter().find(|day| day.number == number)
+    }
+
+    /// The module path of a day, like `y2025::day7`. Messages about the day are logged with it as
+    /// target, so that they can be filtered like the ones from the day itself.
+    pub fn path(&self, day: &Day) -> String {
+        format!("{}::{}", self.module, day.module)
+    }
+}
+
+/// Builds the [`Day`] of a module below an event, from the solution in it.
+macro_rules! day {
+    ($number:literal, $module:ident :: $($solution:tt)+) => {
+        $crate::registry::Day {
+            number: $number,
+            module: stringify!($module),
+            puzzle: &$module::$($solution)+,
+        }
+    };
+}
+pub(crate) use day;
+
+pub const EVENTS: &[Event] = &[Event {
+    name: "2025",
+    module: "y2025",
+    days: y2025::DAYS,
+}];
+
+/// The event that is run if none is selected: the latest one.
+pub fn default_event() -> &'static Event {
+    EVENTS.last().unwrap()
+}
+
+pub fn event(name: &str) -> Option<&'static Event> {
+    EVENTS.iter().find(|event| event.name == name)
+}
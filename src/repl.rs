@@ -0,0 +1,321 @@
+use std::{
+    io::{BufRead, Write},
+    time::Instant,
+};
+
+use crate::{
+    cli::ReplArgs,
+    log, panic,
+    registry::{Day, Part},
+    solution::Parsed,
+};
+
+const HELP: &str = "\
+Commands:
+    a, b               solve the first or second puzzle
+    set NAME VALUE     change a parameter of the puzzles
+    params             show the parameters and their values
+    inspect [LINES]    show the parsed input, or only its first LINES lines
+    time COMMAND       run another command and show how long it took
+    help               show this list
+    quit               stop, like the end of the input does";
+
+/// Loads the input selected by `args` once, and runs the commands read from `commands` on it.
+///
+/// Failed commands are reported and skipped. Returns whether all of them succeeded.
+pub fn run(args: &ReplArgs, commands: impl BufRead, mut out: impl Write) -> Result<bool, String> {
+    let day = args.day;
+    let (name, input, params) = match args.example {
+        Some(idx) => {
+            let example = &day.puzzle.examples()[idx];
+            let name = format!("day_{} example {}", day.number, idx + 1);
+            (name, example.input.to_string(), example.params)
+        }
+        None => {
+            let input = args
+                .inputs
+                .read(day.number)
+                .map_err(|err| err.to_string())?;
+            (format!("day_{} input", day.number), input, &[][..])
+        }
+    };
+    let input = day.puzzle.normalize(&input);
+    let start = Instant::now();
+    let mut parsed = day
+        .puzzle
+        .parse(&input)
+        .map_err(|err| err.locate(&input).describe(&name))?;
+    for (name, value) in params {
+        parsed.set_param(name, value)?;
+    }
+    writeln!(
+        out,
+        "Loaded {name} in {:.2?}, `help` lists the commands",
+        start.elapsed()
+    )
+    .unwrap();
+
+    let mut session = Session {
+        day,
+        name,
+        input: &input,
+        parsed,
+        out,
+    };
+    let mut succeeded = true;
+    let mut lines = commands.lines();
+    loop {
+        write!(session.out, "> ").unwrap();
+        session.out.flush().unwrap();
+        let Some(line) = lines.next() else {
+            writeln!(session.out).unwrap();
+            break;
+        };
+        let line = line.map_err(|err| format!("failed to read a command: {err}"))?;
+        match line.trim() {
+            "" => continue,
+            "quit" | "exit" => break,
+            command => {
+                log::debug!(target: args.event.path(day), "running `{command}`");
+                if let Err(err) = session.execute(command) {
+                    writeln!(session.out, "error: {err}").unwrap();
+                    succeeded = false;
+                }
+                log::flush();
+            }
+        }
+    }
+
+    Ok(succeeded)
+}
+
+/// A loaded input, and where the results of commands on it go.
+struct Session<'a, W: Write> {
+    day: &'static Day,
+    /// What the input is called in errors, like `day_7 input`.
+    name: String,
+    input: &'a str,
+    parsed: Box<dyn Parsed + 'a>,
+    out: W,
+}
+
+impl<W: Write> Session<'_, W> {
+    fn execute(&mut self, command: &str) -> Result<(), String> {
+        let (name, rest) = command
+            .split_once(char::is_whitespace)
+            .map_or((command, ""), |(name, rest)| (name, rest.trim()));
+        match (name, rest) {
+            ("a", "") => self.solve(Part::A),
+            ("b", "") => self.solve(Part::B),
+            ("set", rest) => {
+                let Some((param, value)) = rest.split_once(char::is_whitespace) else {
+                    return Err("`set` needs a parameter and its value".to_string());
+                };
+                self.parsed.set_param(param, value.trim())
+            }
+            ("params", "") => {
+                let params = self.parsed.params();
+                if params.is_empty() {
+                    writeln!(self.out, "Day {} has no parameters", self.day.number).unwrap();
+                }
+                for (name, value) in params {
+                    writeln!(self.out, "{name} = {value}").unwrap();
+                }
+                Ok(())
+            }
+            ("inspect", rest) => {
+                let limit = match rest {
+                    "" => usize::MAX,
+                    rest => rest
+                        .parse()
+                        .map_err(|_| format!("invalid number of lines: `{rest}`"))?,
+                };
+                let inspected = self.parsed.inspect();
+                let mut lines = inspected.lines();
+                for line in lines.by_ref().take(limit) {
+                    writeln!(self.out, "{line}").unwrap();
+                }
+                let more = lines.count();
+                if more > 0 {
+                    writeln!(self.out, "... {more} more lines").unwrap();
+                }
+                Ok(())
+            }
+            ("time", "") => Err("`time` needs a command to run".to_string()),
+            ("time", rest) => {
+                let start = Instant::now();
+                let result = self.execute(rest);
+                writeln!(self.out, "Time: {:.2?}", start.elapsed()).unwrap();
+                result
+            }
+            ("help", "") => {
+                writeln!(self.out, "{HELP}").unwrap();
+                Ok(())
+            }
+            _ => Err(format!("unknown command: `{command}`, `help` lists them")),
+        }
+    }
+
+    fn solve(&mut self, part: Part) -> Result<(), String> {
+        if !self.day.has_part(part) {
+            return Err(format!("day {} has no puzzle `{part}`", self.day.number));
+        }
+        // A panic only ends the command, so that the input doesn't have to be loaded again.
+        let answer = panic::catch(|| self.parsed.solve(part))
+            .map_err(|panic| panic.to_string())?
+            .map_err(|err| err.locate(self.input).describe(&self.name))?;
+        match &answer.unit {
+            Some(unit) => writeln!(self.out, "Solution: `{answer}` {unit}"),
+            None => writeln!(self.out, "Solution: `{answer}`"),
+        }
+        .unwrap();
+        if let Some(picture) = &answer.picture {
+            for line in picture.lines() {
+                writeln!(self.out, "    {line}").unwrap();
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+    use crate::{
+        answer::Answer,
+        cli::{self, Command, ReplArgs},
+        error::Error,
+        input::Inputs,
+        registry::{self, Day},
+        solution::{Example, Solution},
+    };
+
+    fn repl(args: &[&str], commands: &str) -> (Result<bool, String>, String) {
+        let command = cli::parse_command(["repl"].iter().chain(args).map(|arg| arg.to_string()));
+        let Ok(Command::Repl(args)) = command else {
+            panic!("expected the repl command, got {command:?}");
+        };
+        let mut out = Vec::new();
+        let result = run(&args, commands.as_bytes(), &mut out);
+
+        (result, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn commands() {
+        let (result, out) = repl(
+            &["8", "--example", "1"],
+            "a\nparams\nset circuits 2\na\nset pairs ten\nb\n",
+        );
+        let lines: Vec<_> = out.lines().skip(1).collect();
+
+        assert_eq!(result, Ok(false));
+        assert_eq!(
+            lines,
+            [
+                "> Solution: `40`",
+                "> pairs = 10",
+                "circuits = 3",
+                "> > Solution: `20`",
+                "> error: invalid number of pairs: `ten`",
+                "> Solution: `25272`",
+                "> ",
+            ]
+        );
+    }
+
+    #[test]
+    fn inspect_and_time() {
+        let (result, out) = repl(
+            &["11", "--example=2"],
+            "set root fft\n\ntime b\ninspect 2\nquit\nb\n",
+        );
+        let lines: Vec<_> = out.lines().skip(1).collect();
+
+        assert_eq!(result, Ok(true));
+        assert_eq!(lines[0], "> > > Solution: `2`");
+        assert!(lines[1].starts_with("Time: "));
+        assert_eq!(
+            lines[2..],
+            ["> Graph {", "    nodes: {", "... 82 more lines", "> "]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let (result, out) = repl(&["12", "--example", "1"], "b\nfrobnicate\n");
+
+        assert_eq!(result, Ok(false));
+        assert!(out.contains("error: day 12 has no puzzle `b`"));
+        assert!(out.contains("error: unknown command: `frobnicate`"));
+    }
+
+    #[test]
+    fn single_circuit() {
+        let (result, out) = repl(&["8", "--example", "1"], "set pairs 1000\na\nb\n");
+        let lines: Vec<_> = out.lines().skip(1).collect();
+
+        assert_eq!(result, Ok(false));
+        assert_eq!(
+            lines,
+            [
+                "> > error: day_8 example 1: all junction boxes end up in a single circuit within 1000 pairs",
+                "> Solution: `25272`",
+                "> ",
+            ]
+        );
+    }
+
+    /// A solver that panics on its second puzzle.
+    #[derive(Clone)]
+    struct Panicking;
+
+    impl Solution for Panicking {
+        type Input<'a> = &'a str;
+
+        const EXAMPLES: &'static [Example] = &[Example {
+            input: "root\n",
+            part_a: None,
+            part_b: None,
+            params: &[],
+        }];
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+            Ok(input)
+        }
+
+        fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+            Ok(input.len().into())
+        }
+
+        fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+            panic!("no root in `{}`", input.trim());
+        }
+    }
+
+    static PANICKING: Day = Day {
+        number: 98,
+        module: "panicking",
+        puzzle: &Panicking,
+    };
+
+    #[test]
+    fn panics() {
+        let args = ReplArgs {
+            event: registry::default_event(),
+            day: &PANICKING,
+            inputs: Inputs::default(),
+            example: Some(0),
+        };
+        let mut out = Vec::new();
+        let result = run(&args, "b\na\n".as_bytes(), &mut out);
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = out.lines().skip(1).collect();
+
+        assert_eq!(result, Ok(false));
+        assert!(lines[0].starts_with("> error: panicked at src/repl.rs:"));
+        assert!(lines[0].ends_with(": no root in `root`"));
+        assert_eq!(lines[1..], ["> Solution: `5`", "> "]);
+    }
+}
@@ -0,0 +1,783 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+    },
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answer::Answer,
+    bench::{self, Stats},
+    cache::{self, Cache},
+    cancel,
+    check::{Answers, Status},
+    cli::Args,
+    error::Error,
+    history::Regression,
+    log,
+    memory::{self, Usage},
+    panic::{self, Panic},
+    registry::{Day, Part},
+    solution::Parsed,
+};
+
+/// The result of running one puzzle.
+#[derive(Debug)]
+pub struct Outcome {
+    pub day: &'static Day,
+    pub part: Part,
+    /// The number of the example that was solved instead of the input, starting at 1.
+    pub example: Option<usize>,
+    pub answer: Option<Answer>,
+    /// Set if the answer was taken from the cache instead of solving the puzzle.
+    pub cached: bool,
+    pub elapsed: Duration,
+    pub error: Option<String>,
+    /// Set if the puzzle was given up on after `--timeout`.
+    pub timed_out: bool,
+    /// Set to the panic message and its location if the solver panicked.
+    pub panic: Option<String>,
+    pub check: Option<Status>,
+    /// Set if the puzzle got slower than its baseline in the timing history.
+    pub regression: Option<Regression>,
+    pub bench: Option<Bench>,
+    /// The heap usage of parsing and solving, if it was tracked.
+    pub memory: Option<Usage>,
+}
+
+#[derive(Debug)]
+pub struct Bench {
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Outcome {
+    /// Whether this puzzle could not be solved in time, panicked, was solved incorrectly, or got
+    /// slower.
+    pub fn failed(&self) -> bool {
+        self.error.is_some()
+            || self.timed_out
+            || self.panic.is_some()
+            || matches!(self.check, Some(Status::Fail { .. }))
+            || self.regression.is_some()
+    }
+}
+
+/// A puzzle to solve, either on the input or on one of the examples of its day.
+#[derive(Debug)]
+struct Job {
+    day: &'static Day,
+    part: Part,
+    /// The index of the example in [`Puzzle::examples`](crate::solution::Puzzle::examples).
+    example: Option<usize>,
+}
+
+/// Runs the selected puzzles, reporting each outcome in day order as soon as it is known.
+///
+/// With more than one job, the puzzles are solved concurrently on a pool of `args.jobs` threads.
+/// The answers found on the inputs are added to `cache`, and with `args.cached` the ones already
+/// in it are reused, unless the puzzles are benchmarked or their memory usage is tracked.
+pub fn run(
+    args: &Args,
+    answers: Option<&Answers>,
+    mut cache: Option<&mut Cache>,
+    mut report: impl FnMut(Outcome),
+) {
+    let jobs: Vec<_> = if args.example {
+        // Puzzles are only run on the examples that list an answer for them.
+        args.selection
+            .iter()
+            .flat_map(|&(day, part)| {
+                let examples = day.puzzle.examples().iter().enumerate();
+                examples
+                    .filter(move |(_, example)| example.answer(part).is_some())
+                    .map(move |(idx, _)| Job {
+                        day,
+                        part,
+                        example: Some(idx),
+                    })
+            })
+            .collect()
+    } else {
+        args.selection
+            .iter()
+            .map(|&(day, part)| Job {
+                day,
+                part,
+                example: None,
+            })
+            .collect()
+    };
+
+    // Both puzzles of a day use the same input, so we only read it once.
+    let mut inputs = HashMap::new();
+    for job in jobs.iter().filter(|job| job.example.is_none()) {
+        let _ = inputs.entry(job.day.number).or_insert_with(|| {
+            args.inputs
+                .read(job.day.number)
+                .map(|input| job.day.puzzle.normalize(&input))
+                .map_err(|err| err.to_string())
+        });
+    }
+    let checksums: HashMap<_, _> = match cache {
+        Some(_) => inputs
+            .iter()
+            .filter_map(|(&day, input)| Some((day, cache::checksum(input.as_ref().ok()?))))
+            .collect(),
+        None => HashMap::new(),
+    };
+    // Cached answers come without benchmarks and memory usage, so those puzzles are solved again.
+    let reuse = args.cached && args.bench.is_none() && !args.memory;
+    if args.cached && !reuse {
+        log::warning!(
+            "not reusing cached answers, as benchmarks and memory usage need the puzzles solved"
+        );
+    }
+    let mut hits = HashMap::new();
+    if let Some(cache) = cache.as_deref().filter(|_| reuse) {
+        for job in jobs.iter().filter(|job| job.example.is_none()) {
+            let Some(checksum) = checksums.get(&job.day.number) else {
+                continue;
+            };
+            let version = job.day.puzzle.version();
+            if let Some(answer) = cache.get(job.day.number, job.part, version, checksum) {
+                let _ = hits.insert((job.day.number, job.part), answer);
+            }
+        }
+    }
+    let mut report = |outcome: Outcome| {
+        if let Some(cache) = cache.as_deref_mut()
+            && let Some(answer) = &outcome.answer
+            && outcome.example.is_none()
+        {
+            let (day, part) = (outcome.day.number, outcome.part);
+            cache.insert(
+                day,
+                part,
+                outcome.day.puzzle.version(),
+                &checksums[&day],
+                answer,
+            );
+        }
+        report(outcome);
+    };
+    let solve = |job: &Job| match job.example {
+        Some(idx) => {
+            let input = job
+                .day
+                .puzzle
+                .normalize(job.day.puzzle.examples()[idx].input);
+            solve(job, Ok(&input), None, args, answers)
+        }
+        None => {
+            let input = inputs[&job.day.number].as_deref();
+            let cached = hits.get(&(job.day.number, job.part)).cloned();
+            solve(job, input.map_err(String::as_str), cached, args, answers)
+        }
+    };
+
+    if args.jobs <= 1 {
+        jobs.iter().map(solve).for_each(report);
+        return;
+    }
+
+    let next_job = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    std::thread::scope(|s| {
+        for _ in 0..args.jobs.min(jobs.len()) {
+            let sender = sender.clone();
+            let _ = s.spawn(|| {
+                let sender = sender;
+                loop {
+                    let idx = next_job.fetch_add(1, Ordering::Relaxed);
+                    let Some(job) = jobs.get(idx) else {
+                        break;
+                    };
+                    if sender.send((idx, solve(job))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        // Outcomes arrive in any order, so hold them back until all previous ones were reported.
+        let mut pending = BTreeMap::new();
+        let mut next_report = 0;
+        for (idx, outcome) in receiver {
+            let _ = pending.insert(idx, outcome);
+            while let Some(outcome) = pending.remove(&next_report) {
+                report(outcome);
+                next_report += 1;
+            }
+        }
+    });
+}
+
+fn solve(
+    job: &Job,
+    input: Result<&str, &str>,
+    cached: Option<Answer>,
+    args: &Args,
+    answers: Option<&Answers>,
+) -> Outcome {
+    let &Job { day, part, example } = job;
+    let failed = |err: &str| Outcome {
+        day,
+        part,
+        example: example.map(|idx| idx + 1),
+        answer: None,
+        cached: false,
+        elapsed: Duration::ZERO,
+        error: Some(err.to_string()),
+        timed_out: false,
+        panic: None,
+        check: None,
+        regression: None,
+        bench: None,
+        memory: None,
+    };
+    let input = match input {
+        Ok(input) => input,
+        Err(err) => return failed(err),
+    };
+    let target = args.event.path(day);
+    if let Some(answer) = cached {
+        log::debug!(target: target, "reusing the cached answer to {part}: `{answer}`");
+        return Outcome {
+            check: answers.map(|answers| answers.check(day.number, part, &answer)),
+            answer: Some(answer),
+            cached: true,
+            error: None,
+            ..failed("")
+        };
+    }
+    match job.example {
+        Some(idx) => log::debug!(target: target, "solving {part} on example {}", idx + 1),
+        None => log::debug!(target: target, "solving {part} on the input"),
+    }
+    let example = example.map(|idx| &day.puzzle.examples()[idx]);
+    let params = example.map_or(&[][..], |example| example.params);
+
+    let attempt = match args.timeout {
+        Some(timeout) => {
+            attempt_with_timeout(day, part, input, params, args.memory, args.bench, timeout)
+        }
+        None => Some(attempt(day, part, input, params, args.memory, args.bench)),
+    };
+    let Some(Attempt {
+        answer,
+        elapsed,
+        memory,
+        bench,
+    }) = attempt
+    else {
+        log::warning!(
+            target: target,
+            "gave up on {part} after {:.2?}, the solver keeps running until it notices",
+            args.timeout.unwrap_or_default()
+        );
+        return Outcome {
+            elapsed: args.timeout.unwrap_or_default(),
+            error: None,
+            timed_out: true,
+            ..failed("")
+        };
+    };
+    let answer = match answer {
+        Ok(Ok(answer)) => answer,
+        Ok(Err(err)) => {
+            let name = match job.example {
+                Some(idx) => format!("day_{} example {}", day.number, idx + 1),
+                None => format!("day_{} input", day.number),
+            };
+            log::debug!(target: target, "{part} failed after {elapsed:.2?}: {}", err.message);
+            return failed(&err.describe(&name));
+        }
+        Err(panic) => {
+            log::debug!(target: target, "{part} {panic}");
+            return Outcome {
+                elapsed,
+                error: None,
+                panic: Some(panic.to_string()),
+                memory,
+                ..failed("")
+            };
+        }
+    };
+    log::debug!(target: target, "solved {part} in {elapsed:.2?}: `{answer}`");
+
+    let check = match example.and_then(|example| example.answer(part)) {
+        Some(expected) => Some(Status::compare(&answer, expected)),
+        None => answers.map(|answers| answers.check(day.number, part, &answer)),
+    };
+    let (bench, error) = match bench {
+        Some(Ok(bench)) => (Some(bench), None),
+        Some(Err(err)) => {
+            log::debug!(target: target, "failed to benchmark {part}: {err}");
+            (None, Some(format!("failed to benchmark: {err}")))
+        }
+        None => (None, None),
+    };
+
+    Outcome {
+        day,
+        part,
+        example: job.example.map(|idx| idx + 1),
+        answer: Some(answer),
+        cached: false,
+        elapsed,
+        error,
+        timed_out: false,
+        panic: None,
+        check,
+        regression: None,
+        bench,
+        memory,
+    }
+}
+
+/// Parses and solves a puzzle once.
+#[derive(Debug)]
+struct Attempt {
+    /// The answer, an error if the puzzle could not be solved, or the panic of the solver.
+    answer: Result<Result<Answer, Error>, Panic>,
+    elapsed: Duration,
+    memory: Option<Usage>,
+    /// The benchmark of a solved puzzle, if it was asked for.
+    bench: Option<Result<Bench, String>>,
+}
+
+fn parse<'a>(
+    day: &Day,
+    input: &'a str,
+    params: &[(&str, &str)],
+) -> Result<Box<dyn Parsed + 'a>, Error> {
+    let mut parsed = day.puzzle.parse(input)?;
+    for (name, value) in params {
+        parsed.set_param(name, value).map_err(Error::new)?;
+    }
+
+    Ok(parsed)
+}
+
+fn attempt(
+    day: &Day,
+    part: Part,
+    input: &str,
+    params: &[(&str, &str)],
+    track_memory: bool,
+    bench: Option<bench::Config>,
+) -> Attempt {
+    // Errors can only be located while the input they point into is still around.
+    let run = || {
+        panic::catch(|| {
+            parse(day, input, params)
+                .and_then(|parsed| parsed.solve(part))
+                .map_err(|err| err.locate(input))
+        })
+    };
+    let start = Instant::now();
+    let (answer, memory) = if track_memory {
+        let (answer, usage) = memory::track(run);
+        (answer, Some(usage))
+    } else {
+        (run(), None)
+    };
+
+    let elapsed = start.elapsed();
+    let bench = bench
+        .filter(|_| matches!(answer, Ok(Ok(_))))
+        .map(|config| benchmark(day, part, input, params, config));
+
+    Attempt {
+        answer,
+        elapsed,
+        memory,
+        bench,
+    }
+}
+
+/// Measures parsing and solving a puzzle that was solved before.
+fn benchmark(
+    day: &Day,
+    part: Part,
+    input: &str,
+    params: &[(&str, &str)],
+    config: bench::Config,
+) -> Result<Bench, String> {
+    let bench = panic::catch(|| {
+        let parsed = parse(day, input, params)?;
+        Ok::<_, Error>(Bench {
+            parse: bench::measure(config, || day.puzzle.parse(input)),
+            solve: bench::measure(config, || parsed.solve(part)),
+        })
+    });
+
+    match bench {
+        Ok(Ok(bench)) => Ok(bench),
+        Ok(Err(err)) => Err(err.message),
+        Err(panic) => Err(panic.to_string()),
+    }
+}
+
+/// Runs [`attempt`] on a thread of its own, giving up on it after `timeout`.
+///
+/// Returns `None` if the time ran out before the puzzle was solved. If it runs out while
+/// benchmarking, the answer is kept and the benchmark fails. Either way, the solver is then
+/// cancelled, but keeps running in the background until it notices, or until the process exits.
+fn attempt_with_timeout(
+    day: &'static Day,
+    part: Part,
+    input: &str,
+    params: &'static [(&'static str, &'static str)],
+    track_memory: bool,
+    bench: Option<bench::Config>,
+    timeout: Duration,
+) -> Option<Attempt> {
+    let deadline = Instant::now() + timeout;
+    let token = cancel::Token::default();
+    let worker_token = token.clone();
+    let input = input.to_string();
+    let (sender, receiver) = mpsc::channel();
+    let (bench_sender, bench_receiver) = mpsc::channel();
+    let spawned = std::thread::Builder::new()
+        .name(format!("day {}{part}", day.number))
+        .spawn(move || {
+            cancel::with(worker_token, || {
+                // The answer is sent on its own, so that it is not lost if benchmarking times out.
+                let attempt = attempt(day, part, &input, params, track_memory, None);
+                let solved = matches!(attempt.answer, Ok(Ok(_)));
+                let _ = sender.send(attempt);
+                if let Some(config) = bench.filter(|_| solved) {
+                    let _ = bench_sender.send(benchmark(day, part, &input, params, config));
+                }
+            });
+        });
+    let failed = |err: String| Attempt {
+        answer: Ok(Err(Error::new(err))),
+        elapsed: Duration::ZERO,
+        memory: None,
+        bench: None,
+    };
+    if let Err(err) = spawned {
+        return Some(failed(format!("failed to start the solver: {err}")));
+    }
+
+    match receiver.recv_timeout(timeout) {
+        Ok(mut attempt) if bench.is_some() && matches!(attempt.answer, Ok(Ok(_))) => {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            attempt.bench = Some(match bench_receiver.recv_timeout(remaining) {
+                Ok(bench) => bench,
+                Err(RecvTimeoutError::Timeout) => {
+                    token.cancel();
+                    Err(format!("gave up after {timeout:.2?}"))
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    Err("the benchmark stopped without results".to_string())
+                }
+            });
+            Some(attempt)
+        }
+        Ok(attempt) => Some(attempt),
+        Err(RecvTimeoutError::Timeout) => {
+            token.cancel();
+            None
+        }
+        // Panics of the solver are caught by `attempt`, so this is a panic outside of it.
+        Err(RecvTimeoutError::Disconnected) => {
+            log::error!(
+                "the solver of day {}{part} stopped without an answer",
+                day.number
+            );
+            Some(failed("the solver stopped without an answer".to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{attempt, attempt_with_timeout, run};
+    use crate::{
+        answer::Answer,
+        bench, cancel,
+        check::Status,
+        cli,
+        error::Error,
+        registry::{self, Day, Part},
+        solution::{Example, Solution},
+    };
+
+    /// A solver that only stops when it gets cancelled.
+    #[derive(Clone)]
+    struct Endless;
+
+    impl Solution for Endless {
+        type Input<'a> = ();
+
+        const EXAMPLES: &'static [Example] = &[];
+
+        fn parse<'a>(&self, _input: &'a str) -> Result<Self::Input<'a>, Error> {
+            Ok(())
+        }
+
+        fn part_a(&self, _input: &Self::Input<'_>) -> Result<Answer, Error> {
+            while !cancel::is_cancelled() {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            Ok(0.into())
+        }
+
+        fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+            self.part_a(input)
+        }
+    }
+
+    static ENDLESS: Day = Day {
+        number: 99,
+        module: "endless",
+        puzzle: &Endless,
+    };
+
+    /// A solver that takes a while for every puzzle.
+    #[derive(Clone)]
+    struct Slow;
+
+    impl Solution for Slow {
+        type Input<'a> = ();
+
+        const EXAMPLES: &'static [Example] = &[];
+
+        fn parse<'a>(&self, _input: &'a str) -> Result<Self::Input<'a>, Error> {
+            Ok(())
+        }
+
+        fn part_a(&self, _input: &Self::Input<'_>) -> Result<Answer, Error> {
+            std::thread::sleep(Duration::from_millis(10));
+            Ok(1.into())
+        }
+
+        fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+            self.part_a(input)
+        }
+    }
+
+    static SLOW: Day = Day {
+        number: 97,
+        module: "slow",
+        puzzle: &Slow,
+    };
+
+    /// A solver that panics on its second puzzle.
+    #[derive(Clone)]
+    struct Panicking;
+
+    impl Solution for Panicking {
+        type Input<'a> = &'a str;
+
+        const EXAMPLES: &'static [Example] = &[];
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+            Ok(input)
+        }
+
+        fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+            Ok(input.len().into())
+        }
+
+        fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+            panic!("no root in `{input}`");
+        }
+    }
+
+    static PANICKING: Day = Day {
+        number: 98,
+        module: "panicking",
+        puzzle: &Panicking,
+    };
+
+    #[test]
+    fn jobs_keep_day_order() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-runner-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("2025")).unwrap();
+        std::fs::write(
+            dir.join("2025/day_1_a_input.txt"),
+            "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("2025/day_3_a_input.txt"),
+            "987654321111111\n811111111111119\n234234234234278\n818181911112111\n",
+        )
+        .unwrap();
+        let args = cli::parse(
+            [
+                "3",
+                "1",
+                "2",
+                "--jobs",
+                "4",
+                "--input-dir",
+                dir.to_str().unwrap(),
+            ]
+            .into_iter()
+            .map(String::from),
+        )
+        .unwrap();
+
+        let mut outcomes = Vec::new();
+        run(&args, None, None, |outcome| {
+            outcomes.push((outcome.day.number, outcome.part, outcome.answer))
+        });
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            outcomes,
+            [
+                (1, Part::A, Some(3.into())),
+                (1, Part::B, Some(6.into())),
+                (2, Part::A, None),
+                (2, Part::B, None),
+                (3, Part::A, Some(357.into())),
+                (3, Part::B, Some(3121910778619_u64.into())),
+            ]
+        );
+    }
+
+    #[test]
+    fn input_errors() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-errors-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("2025")).unwrap();
+        std::fs::write(dir.join("2025/day_7_a_input.txt"), "..S..\n.....\n..^x.\n").unwrap();
+        let args = cli::parse(
+            ["7a", "--input-dir", dir.to_str().unwrap()]
+                .into_iter()
+                .map(String::from),
+        )
+        .unwrap();
+
+        let mut errors = Vec::new();
+        run(&args, None, None, |outcome| errors.push(outcome.error));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            errors,
+            [Some("day_7 input:3:4: unexpected tile 'x'".to_string())]
+        );
+    }
+
+    #[test]
+    fn examples() {
+        let args = cli::parse(["8", "11", "--example"].into_iter().map(String::from)).unwrap();
+
+        let mut outcomes = Vec::new();
+        run(&args, None, None, |outcome| {
+            outcomes.push((
+                outcome.day.number,
+                outcome.part,
+                outcome.example,
+                outcome.check,
+            ))
+        });
+
+        assert_eq!(
+            outcomes,
+            [
+                (8, Part::A, Some(1), Some(Status::Pass)),
+                (8, Part::B, Some(1), Some(Status::Pass)),
+                (11, Part::A, Some(1), Some(Status::Pass)),
+                (11, Part::B, Some(2), Some(Status::Pass)),
+            ]
+        );
+    }
+
+    #[test]
+    fn panics() {
+        let answer = attempt(&PANICKING, Part::A, "abc", &[], true, None).answer;
+        assert_eq!(answer, Ok(Ok(3.into())));
+
+        let panic = attempt(&PANICKING, Part::B, "abc", &[], true, None)
+            .answer
+            .unwrap_err();
+        assert_eq!(panic.message, "no root in `abc`");
+        assert!(panic.location.unwrap().starts_with("src/runner.rs:"));
+
+        let attempt = attempt_with_timeout(
+            &PANICKING,
+            Part::B,
+            "",
+            &[],
+            false,
+            None,
+            Duration::from_secs(60),
+        );
+        assert_eq!(
+            attempt.unwrap().answer.unwrap_err().message,
+            "no root in ``"
+        );
+    }
+
+    #[test]
+    fn timeout() {
+        let attempt = attempt_with_timeout(
+            &ENDLESS,
+            Part::A,
+            "",
+            &[],
+            false,
+            None,
+            Duration::from_millis(20),
+        );
+        assert!(attempt.is_none());
+
+        let attempt = attempt_with_timeout(
+            registry::default_event().find(1).unwrap(),
+            Part::A,
+            "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n",
+            &[],
+            false,
+            None,
+            Duration::from_secs(60),
+        );
+        assert_eq!(attempt.unwrap().answer, Ok(Ok(3.into())));
+    }
+
+    #[test]
+    fn bench_timeout() {
+        let config = bench::Config {
+            warmup: 0,
+            iterations: 1000,
+        };
+        let timed_out = attempt_with_timeout(
+            &SLOW,
+            Part::A,
+            "",
+            &[],
+            false,
+            Some(config),
+            Duration::from_millis(200),
+        )
+        .unwrap();
+        assert_eq!(timed_out.answer, Ok(Ok(1.into())));
+        assert_eq!(
+            timed_out.bench.unwrap().unwrap_err(),
+            "gave up after 200.00ms"
+        );
+
+        let config = bench::Config {
+            warmup: 0,
+            iterations: 2,
+        };
+        let bench = attempt(&SLOW, Part::A, "", &[], false, Some(config)).bench;
+        assert_eq!(bench.unwrap().unwrap().solve.iterations, 2);
+        assert!(
+            attempt(&PANICKING, Part::B, "", &[], false, Some(config))
+                .bench
+                .is_none()
+        );
+    }
+}
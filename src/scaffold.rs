@@ -0,0 +1,217 @@
+use std::{
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{cli::NewDayArgs, registry::Event};
+
+/// The module of a new day, with stubs for both puzzles and a test for the first example.
+pub fn module(day: u8) -> String {
+    format!(
+        r#"use crate::{{
+    answer::Answer,
+    error::Error,
+    solution::{{Example, Solution}},
+}};
+
+/// Day {day}.
+#[derive(Debug, Clone)]
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    /// The lines of the input.
+    type Input<'a> = Vec<&'a str>;
+
+    const EXAMPLES: &'static [Example] = &[Example {{
+        input: "",
+        part_a: None,
+        part_b: None,
+        params: &[],
+    }}];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {{
+        Ok(input.lines().collect())
+    }}
+
+    fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {{
+        Ok(solve_puzzle_a(input).into())
+    }}
+
+    fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {{
+        Ok(solve_puzzle_b(input).into())
+    }}
+}}
+
+fn solve_puzzle_a(lines: &[&str]) -> usize {{
+    let _ = lines;
+    todo!("solve the first puzzle of day {day}")
+}}
+
+fn solve_puzzle_b(lines: &[&str]) -> usize {{
+    let _ = lines;
+    todo!("solve the second puzzle of day {day}")
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::Day{day};
+    use crate::{{registry::Part, solution::assert_example}};
+
+    #[test]
+    fn example() {{
+        assert_example(Day{day}, 0, Part::A);
+    }}
+}}
+"#
+    )
+}
+
+/// Adds `pub mod dayN;` to the module of the event, keeping the modules sorted.
+fn register_module(event: &str, day: u8) -> Result<String, String> {
+    let line = format!("pub mod day{day};");
+    let mut lines: Vec<_> = event.lines().collect();
+    if lines.contains(&line.as_str()) {
+        return Err(format!("`day{day}` is already declared"));
+    }
+    let modules = lines
+        .iter()
+        .position(|l| l.starts_with("pub mod "))
+        .ok_or("no modules are declared")?;
+    let name = format!("day{day}");
+    let idx = lines[modules..]
+        .iter()
+        .position(|l| {
+            l.strip_prefix("pub mod ")
+                .and_then(|l| l.strip_suffix(';'))
+                .is_none_or(|module| module > name.as_str())
+        })
+        .map_or(lines.len(), |idx| modules + idx);
+    lines.insert(idx, &line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the day to `DAYS` in the module of the event, keeping the days sorted.
+fn register_day(event: &str, day: u8) -> Result<String, String> {
+    let mut lines: Vec<_> = event.lines().collect();
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with("pub const DAYS"))
+        .ok_or("`DAYS` is missing")?;
+    let mut idx = start + 1;
+    while let Some(number) = lines
+        .get(idx)
+        .and_then(|l| l.trim().strip_prefix("day!("))
+        .and_then(|l| l.split_once(','))
+        .and_then(|(number, _)| number.parse::<u8>().ok())
+    {
+        if number == day {
+            return Err(format!("day {day} is already registered"));
+        }
+        if number > day {
+            break;
+        }
+        idx += 1;
+    }
+    let line = format!("    day!({day}, day{day}::Day{day}),");
+    lines.insert(idx, &line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates the module of a new day of `event` in `src`, and registers it with the runner.
+///
+/// Existing files are never overwritten. Returns the files that were created or changed.
+pub fn new_day(src: &Path, event: &Event, day: u8) -> Result<Vec<PathBuf>, String> {
+    let read = |path: &Path| {
+        std::fs::read_to_string(path)
+            .map_err(|err| format!("failed to read `{}`: {err}", path.display()))
+    };
+    let write = |path: &Path, content: &str| {
+        std::fs::write(path, content)
+            .map_err(|err| format!("failed to write `{}`: {err}", path.display()))
+    };
+
+    // Everything is checked before anything is written, so a refused day leaves no traces.
+    let event_path = src.join(format!("{}.rs", event.module));
+    let in_event = |err| format!("{err} in `{}`", event_path.display());
+    let registered = register_module(&read(&event_path)?, day).map_err(in_event)?;
+    let registered = register_day(&registered, day).map_err(in_event)?;
+
+    let module_path = src.join(event.module).join(format!("day{day}.rs"));
+    let mut file = match std::fs::File::create_new(&module_path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+            return Err(format!(
+                "`{}` already exists, not overwriting it",
+                module_path.display()
+            ));
+        }
+        Err(err) => {
+            return Err(format!(
+                "failed to create `{}`: {err}",
+                module_path.display()
+            ));
+        }
+    };
+    file.write_all(module(day).as_bytes())
+        .map_err(|err| format!("failed to write `{}`: {err}", module_path.display()))?;
+    write(&event_path, &registered)?;
+
+    Ok(vec![module_path, event_path])
+}
+
+pub fn run(args: &NewDayArgs, mut out: impl Write) -> Result<bool, String> {
+    let day = args.day;
+    for path in new_day(&args.src, args.event, day)? {
+        writeln!(out, "Wrote `{}`", path.display()).unwrap();
+    }
+    writeln!(
+        out,
+        "Add the examples to `Day{day}::EXAMPLES`, and download the input with \
+         `aoc2025 fetch --event {} {day}`.",
+        args.event.name
+    )
+    .unwrap();
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{module, new_day};
+    use crate::registry;
+
+    #[test]
+    fn scaffold() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-scaffold-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("y2025")).unwrap();
+        std::fs::write(dir.join("y2025.rs"), include_str!("y2025.rs")).unwrap();
+        let event = registry::event("2025").unwrap();
+
+        assert_eq!(new_day(&dir, event, 13).unwrap().len(), 2);
+        let registered = std::fs::read_to_string(dir.join("y2025.rs")).unwrap();
+        assert!(registered.contains("pub mod day12;\npub mod day13;\npub mod day2;\n"));
+        assert!(registered.contains("day12::Day12),\n    day!(13, day13::Day13),\n];"));
+        assert_eq!(
+            std::fs::read_to_string(dir.join("y2025/day13.rs")).unwrap(),
+            module(13)
+        );
+
+        // Registered days, and existing modules, are left alone.
+        std::fs::write(dir.join("y2025/day14.rs"), "// Work in progress\n").unwrap();
+        assert!(new_day(&dir, event, 13).is_err());
+        assert!(new_day(&dir, event, 5).is_err());
+        assert!(new_day(&dir, event, 14).is_err());
+        assert_eq!(
+            std::fs::read_to_string(dir.join("y2025.rs")).unwrap(),
+            registered
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.join("y2025/day14.rs")).unwrap(),
+            "// Work in progress\n"
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,41 @@
+/// The puzzle server used if no other one is configured.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// The environment variable holding the session cookie of the puzzle server.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// The environment variable that overrides [`DEFAULT_BASE_URL`].
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// The puzzle server that inputs are fetched from and answers are submitted to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Server {
+    pub base_url: String,
+    pub session: String,
+}
+
+impl Server {
+    /// Reads the session cookie from the environment. The base URL is read from there as well,
+    /// unless it is given.
+    pub fn from_env(base_url: Option<&str>) -> Result<Self, String> {
+        let session = std::env::var(SESSION_VAR).map_err(|_| {
+            format!("set `{SESSION_VAR}` to the session cookie of the puzzle server")
+        })?;
+        let base_url = match base_url {
+            Some(base_url) => base_url.to_string(),
+            None => std::env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+        };
+
+        Ok(Self { base_url, session })
+    }
+
+    /// The URL of something belonging to a day's puzzles in an event, like its `input`.
+    pub fn day_url(&self, event: &str, day: u8, path: &str) -> String {
+        format!(
+            "{}/{event}/day/{day}/{path}",
+            self.base_url.trim_end_matches('/')
+        )
+    }
+
+    pub fn cookie(&self) -> (&'static str, String) {
+        ("Cookie", format!("session={}", self.session))
+    }
+}
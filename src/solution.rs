@@ -0,0 +1,166 @@
+use std::fmt::Debug;
+
+use crate::{answer::Answer, error::Error, registry::Part};
+
+// Days can change how their input is normalized, so implementing the trait needs the type.
+pub use crate::input::Normalize;
+
+/// The solution of a day, split into parsing the input and solving each puzzle on the parsed input.
+pub trait Solution {
+    /// The parsed input, shared by both puzzles.
+    type Input<'a>: Debug;
+
+    /// Whether the day has a second puzzle. The last day of the event only has one.
+    const HAS_PART_B: bool = true;
+
+    /// The examples from the puzzle description.
+    const EXAMPLES: &'static [Example];
+
+    /// How the input is cleaned up before it is parsed, examples included.
+    const NORMALIZE: Normalize = Normalize::ALL;
+
+    /// The version of the solution. Bump it whenever a change could change the answers, so that
+    /// cached answers are not reused.
+    const VERSION: u32 = 1;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error>;
+
+    fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error>;
+
+    fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error>;
+
+    /// Changes a parameter of the solution, for days whose examples are solved differently than
+    /// the real input.
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        let _ = value;
+        Err(format!("unknown parameter `{name}`"))
+    }
+
+    /// The parameters of the solution and their current values, as `(name, value)`.
+    fn params(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+/// An example from a puzzle description, and its answers.
+#[derive(Debug)]
+pub struct Example {
+    pub input: &'static str,
+    /// The answer to the first puzzle, if this example is meant for it.
+    pub part_a: Option<&'static str>,
+    /// The answer to the second puzzle, if this example is meant for it.
+    pub part_b: Option<&'static str>,
+    /// Parameters to set before solving the example, as `(name, value)`.
+    pub params: &'static [(&'static str, &'static str)],
+}
+
+impl Example {
+    pub fn answer(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::A => self.part_a,
+            Part::B => self.part_b,
+        }
+    }
+}
+
+/// Object-safe view of a [`Solution`], so that the registry can hold all days in one list.
+pub trait Puzzle: Sync {
+    fn has_part(&self, part: Part) -> bool;
+
+    fn examples(&self) -> &'static [Example];
+
+    /// See [`Solution::VERSION`].
+    fn version(&self) -> u32;
+
+    /// Applies the [`Solution::NORMALIZE`] policy of the day to an input.
+    fn normalize(&self, input: &str) -> String;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, Error>;
+}
+
+/// An input parsed by a [`Puzzle`], ready to be solved.
+pub trait Parsed {
+    fn solve(&self, part: Part) -> Result<Answer, Error>;
+
+    /// See [`Solution::set_param`].
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String>;
+
+    /// See [`Solution::params`].
+    fn params(&self) -> Vec<(&'static str, String)>;
+
+    /// Shows the parsed input, to see what the parser made of it.
+    fn inspect(&self) -> String;
+}
+
+struct ParsedInput<'a, S: Solution> {
+    solution: S,
+    input: S::Input<'a>,
+}
+
+impl<S: Solution> Parsed for ParsedInput<'_, S> {
+    fn solve(&self, part: Part) -> Result<Answer, Error> {
+        match part {
+            Part::A => self.solution.part_a(&self.input),
+            Part::B => self.solution.part_b(&self.input),
+        }
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        self.solution.set_param(name, value)
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        self.solution.params()
+    }
+
+    fn inspect(&self) -> String {
+        format!("{:#?}", self.input)
+    }
+}
+
+impl<S: Solution + Clone + Sync + 'static> Puzzle for S {
+    fn has_part(&self, part: Part) -> bool {
+        part == Part::A || S::HAS_PART_B
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+
+    fn version(&self) -> u32 {
+        S::VERSION
+    }
+
+    fn normalize(&self, input: &str) -> String {
+        S::NORMALIZE.apply(input)
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, Error> {
+        Ok(Box::new(ParsedInput {
+            solution: self.clone(),
+            input: Solution::parse(self, input)?,
+        }))
+    }
+}
+
+/// Solves an example of `solution` and checks the answer against the one it lists.
+#[cfg(test)]
+pub(crate) fn assert_example<S: Solution + Clone + Sync + 'static>(
+    solution: S,
+    example: usize,
+    part: Part,
+) {
+    let example = &S::EXAMPLES[example];
+    let input = Puzzle::normalize(&solution, example.input);
+    let describe = |err: Error| err.locate(&input).describe("example");
+    let mut parsed =
+        Puzzle::parse(&solution, &input).unwrap_or_else(|err| panic!("{}", describe(err)));
+    for (name, value) in example.params {
+        parsed.set_param(name, value).unwrap();
+    }
+    let answer = parsed
+        .solve(part)
+        .unwrap_or_else(|err| panic!("{}", describe(err)));
+
+    assert_eq!(Some(answer.to_string().as_str()), example.answer(part));
+}
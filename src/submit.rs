@@ -0,0 +1,330 @@
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::SystemTime,
+};
+
+use crate::{
+    cli::{self, SubmitArgs},
+    error::Error,
+    http::{self, Request},
+    registry::Part,
+    server::Server,
+};
+
+/// What the puzzle server made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Incorrect,
+    /// Answered too soon after the last one. The answer was not checked.
+    RateLimited {
+        /// How long to wait, as the server put it, like `4m 32s`.
+        wait: Option<String>,
+    },
+    /// The puzzle is not unlocked yet, or was already solved.
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    /// Reads the verdict from the page the server responds with.
+    pub fn parse(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Verdict::Correct
+        } else if page.contains("You gave an answer too recently") {
+            let wait = page
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            Verdict::RateLimited { wait }
+        } else if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if page.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if page.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Whether the server judged the answer, so submitting it again would only repeat the verdict.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Correct | Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Incorrect => "incorrect",
+            Verdict::RateLimited { .. } => "rate-limited",
+            Verdict::WrongLevel => "wrong-level",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "incorrect" => Ok(Verdict::Incorrect),
+            "rate-limited" => Ok(Verdict::RateLimited { wait: None }),
+            "wrong-level" => Ok(Verdict::WrongLevel),
+            "unknown" => Ok(Verdict::Unknown),
+            _ => Err(format!("unknown verdict `{s}`")),
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "CORRECT"),
+            Verdict::TooHigh => write!(f, "WRONG (too high)"),
+            Verdict::TooLow => write!(f, "WRONG (too low)"),
+            Verdict::Incorrect => write!(f, "WRONG"),
+            Verdict::RateLimited { wait: Some(wait) } => {
+                write!(f, "RATE LIMITED (wait {wait} before trying again)")
+            }
+            Verdict::RateLimited { wait: None } => write!(f, "RATE LIMITED"),
+            Verdict::WrongLevel => write!(f, "NOT ACCEPTED (puzzle locked or already solved)"),
+            Verdict::Unknown => write!(f, "UNKNOWN (could not read the server's response)"),
+        }
+    }
+}
+
+/// Posts an answer to the puzzle server, and reads its verdict.
+pub fn submit(
+    server: &Server,
+    event: &str,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Verdict, String> {
+    let level = match part {
+        Part::A => 1,
+        Part::B => 2,
+    };
+    let url = server.day_url(event, day, "answer");
+    let response = Request {
+        url: &url,
+        headers: vec![server.cookie()],
+        form: Some(format!(
+            "level={level}&answer={}",
+            http::form_encode(answer)
+        )),
+    }
+    .send()?;
+    if !response.is_success() {
+        return Err(format!(
+            "the puzzle server responded with status {}",
+            response.status
+        ));
+    }
+
+    Ok(Verdict::parse(&response.text()))
+}
+
+/// The local record of submitted answers, with one `<day><part> <verdict> <unix time> <answer>`
+/// entry per line.
+#[derive(Debug)]
+pub struct Log {
+    path: PathBuf,
+    entries: Vec<(u8, Part, Verdict, String)>,
+}
+
+impl Log {
+    /// Reads the log at `path`. A missing file is treated as an empty one.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(format!("failed to read `{}`: {err}", path.display())),
+        };
+        let mut entries = Vec::new();
+        for (idx, line) in content.lines().enumerate() {
+            let invalid = || {
+                format!(
+                    "{}:{}: invalid submission `{line}`",
+                    path.display(),
+                    idx + 1
+                )
+            };
+            let mut fields = line.splitn(4, ' ');
+            let (Some(puzzle), Some(verdict), Some(_), Some(answer)) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(invalid());
+            };
+            let (day, part) = cli::parse_day_part(puzzle).ok_or_else(invalid)?;
+            let verdict = verdict.parse().map_err(|_| invalid())?;
+            entries.push((day, part, verdict, answer.to_string()));
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+
+    /// The final verdict on an answer that was submitted before, if any.
+    pub fn previous(&self, day: u8, part: Part, answer: &str) -> Option<&Verdict> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.0 == day && entry.1 == part && entry.3 == answer)
+            .map(|entry| &entry.2)
+            .find(|verdict| verdict.is_final())
+    }
+
+    pub fn record(
+        &mut self,
+        day: u8,
+        part: Part,
+        answer: &str,
+        verdict: Verdict,
+    ) -> Result<(), String> {
+        let time = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let failed = |err: io::Error| {
+            format!(
+                "failed to record the submission in `{}`: {err}",
+                self.path.display()
+            )
+        };
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(failed)?;
+        writeln!(file, "{day}{part} {} {time} {answer}", verdict.name()).map_err(failed)?;
+        self.entries.push((day, part, verdict, answer.to_string()));
+
+        Ok(())
+    }
+}
+
+/// Submits the answer selected by `args`, solving the puzzle first if no answer was given.
+///
+/// Returns whether the answer was correct.
+pub fn run(args: &SubmitArgs, mut out: impl Write) -> Result<bool, String> {
+    let server = Server::from_env(args.base_url.as_deref())?;
+    let (day, part) = (args.day.number, args.part);
+    let answer = match &args.answer {
+        Some(answer) => answer.clone(),
+        None => {
+            let input = args.inputs.read(day).map_err(|err| err.to_string())?;
+            let input = args.day.puzzle.normalize(&input);
+            let describe = |err: Error| err.locate(&input).describe(&format!("day_{day} input"));
+            let parsed = args.day.puzzle.parse(&input).map_err(describe)?;
+            parsed.solve(part).map_err(describe)?.to_string()
+        }
+    };
+
+    let mut log = Log::load(&args.log)?;
+    if let Some(verdict) = log.previous(day, part, &answer) {
+        writeln!(
+            out,
+            "`{answer}` was already submitted for day {day}{part}: {verdict}"
+        )
+        .unwrap();
+        return Ok(*verdict == Verdict::Correct);
+    }
+    writeln!(out, "Submitting `{answer}` for day {day}{part}").unwrap();
+    let verdict = submit(&server, args.event.name, day, part, &answer)?;
+    writeln!(out, "Verdict: {verdict}").unwrap();
+    let correct = verdict == Verdict::Correct;
+    log.record(day, part, &answer, verdict)?;
+
+    Ok(correct)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Log, Verdict, submit};
+    use crate::{http::stand_in, registry::Part, server::Server};
+
+    #[test]
+    fn submit_to_server() {
+        let page = "<main><article><p>That's not the right answer; your answer is too high. \
+                    Please wait one minute before trying again.</p></article></main>";
+        let (url, stand_in) = stand_in::serve(vec![(200, page.into()), (500, Vec::new())]);
+
+        let server = Server {
+            base_url: url,
+            session: "s3cr3t".to_string(),
+        };
+
+        let verdict = submit(&server, "2025", 7, Part::B, "41 42").unwrap();
+        assert_eq!(verdict, Verdict::TooHigh);
+        assert!(submit(&server, "2025", 7, Part::B, "41 42").is_err());
+
+        let requests = stand_in.join().unwrap();
+        let request = &requests[0];
+        assert!(request.starts_with("POST /2025/day/7/answer HTTP/1.0\r\n"));
+        assert!(request.contains("\r\nCookie: session=s3cr3t\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=41+42"));
+    }
+
+    #[test]
+    fn verdicts() {
+        assert_eq!(
+            Verdict::parse("That's the right answer! You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer. If you're stuck, ..."),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            Verdict::parse("You gave an answer too recently. You have 4m 32s left to wait."),
+            Verdict::RateLimited {
+                wait: Some("4m 32s".to_string())
+            }
+        );
+        assert_eq!(
+            Verdict::parse("You don't seem to be solving the right level."),
+            Verdict::WrongLevel
+        );
+        assert_eq!(Verdict::parse("<html></html>"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn log() {
+        let path = std::env::temp_dir().join(format!("aoc2025-submissions-{}", std::process::id()));
+        let mut log = Log::load(&path).unwrap();
+        log.record(7, Part::A, "21", Verdict::RateLimited { wait: None })
+            .unwrap();
+        log.record(7, Part::A, "21", Verdict::TooLow).unwrap();
+        log.record(7, Part::A, "1 2", Verdict::Correct).unwrap();
+
+        let log = Log::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(log.previous(7, Part::A, "21"), Some(&Verdict::TooLow));
+        assert_eq!(log.previous(7, Part::A, "1 2"), Some(&Verdict::Correct));
+        assert_eq!(log.previous(7, Part::B, "21"), None);
+    }
+}
@@ -0,0 +1,484 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::{Duration, SystemTime},
+};
+
+use crate::{cli::Args, input::Source, log, registry::Part};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Polls the sources and inputs of the selected days for changes, using their modification times.
+#[derive(Debug)]
+pub struct Watcher {
+    sources: Vec<PathBuf>,
+    mtimes: HashMap<PathBuf, Option<SystemTime>>,
+}
+
+/// What changed since the last poll.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// Only inputs changed, so the puzzles can be re-run as they are.
+    Inputs,
+    /// A source changed, so the binary needs to be rebuilt first.
+    Sources,
+}
+
+impl Watcher {
+    pub fn new(args: &Args) -> Result<Self, String> {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        // The days may use any of the common code, so all of it is watched.
+        let mut sources = vec![src.join("common.rs")];
+        if let Ok(entries) = std::fs::read_dir(src.join("common")) {
+            sources.extend(entries.filter_map(|entry| Some(entry.ok()?.path())));
+        }
+        let mut inputs = Vec::new();
+        for (day, _) in &args.selection {
+            let source = src
+                .join(args.event.module)
+                .join(format!("{}.rs", day.module));
+            if sources.contains(&source) {
+                continue;
+            }
+            sources.push(source);
+            if args.example {
+                continue;
+            }
+            match args.inputs.source(day.number) {
+                Source::Path(path) => inputs.push(path),
+                Source::Stdin => {
+                    return Err(format!(
+                        "`--watch` can not re-read the input of day {} from stdin",
+                        day.number
+                    ));
+                }
+            }
+        }
+
+        Ok(Self::from_paths(sources, inputs))
+    }
+
+    fn from_paths(sources: Vec<PathBuf>, inputs: Vec<PathBuf>) -> Self {
+        let mtimes = sources
+            .iter()
+            .chain(&inputs)
+            .map(|path| (path.clone(), mtime(path)))
+            .collect();
+
+        Self { sources, mtimes }
+    }
+
+    pub fn len(&self) -> usize {
+        self.mtimes.len()
+    }
+
+    /// Checks the files once, returning what changed since the last check.
+    pub fn poll(&mut self) -> Option<Change> {
+        let mut change = None;
+        for (path, last) in &mut self.mtimes {
+            let current = mtime(path);
+            if current == *last {
+                continue;
+            }
+            *last = current;
+            log::trace!("`{}` changed", path.display());
+            if self.sources.contains(path) {
+                change = Some(Change::Sources);
+            } else if change.is_none() {
+                change = Some(Change::Inputs);
+            }
+        }
+
+        change
+    }
+
+    /// Blocks until a watched file changes.
+    pub fn wait(&mut self) -> Change {
+        loop {
+            std::thread::sleep(POLL_INTERVAL);
+            if let Some(change) = self.poll() {
+                return change;
+            }
+        }
+    }
+}
+
+/// The modification time of a file, or `None` if it does not exist (yet).
+fn mtime(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// The result of one puzzle in a run, as far as the diff between runs is concerned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+    pub error: Option<String>,
+    pub timed_out: bool,
+}
+
+/// The results of a run, keyed by day, part and example.
+pub type Results = BTreeMap<(u8, Part, Option<usize>), Record>;
+
+/// Re-runs the puzzles whenever a watched file changes, until the process is killed.
+///
+/// The puzzles are solved by a child process running the current binary with `child_args`, so
+/// that changed sources take effect after rebuilding.
+pub fn watch(args: &Args, child_args: &[String], mut out: impl Write) -> Result<(), String> {
+    // Once cargo replaced the binary, the path of the running one no longer exists on Linux, so it
+    // is looked up before the first rebuild.
+    let exe = std::env::current_exe()
+        .map_err(|err| format!("failed to find the current executable: {err}"))?;
+    let mut watcher = Watcher::new(args)?;
+    let files = watcher.len();
+    run_loop(
+        &mut out,
+        files,
+        || Some(watcher.wait()),
+        rebuild,
+        || run_child(&exe, child_args),
+    )
+}
+
+/// Runs the puzzles, then rebuilds and runs them again for every change `wait` returns, until it
+/// returns `None`.
+///
+/// Failed builds and runs are reported, and then wait for the next change like any other run.
+fn run_loop(
+    out: &mut impl Write,
+    files: usize,
+    mut wait: impl FnMut() -> Option<Change>,
+    mut rebuild: impl FnMut() -> Result<bool, String>,
+    mut run: impl FnMut() -> Result<Results, String>,
+) -> Result<(), String> {
+    let mut previous = None;
+    // Whether sources changed since the binary was last built successfully.
+    let mut outdated = false;
+    loop {
+        if outdated && rebuild()? {
+            outdated = false;
+        }
+        if outdated {
+            writeln!(out, "--- Build failed, waiting for changes ---").unwrap();
+        } else {
+            match run() {
+                Ok(results) => {
+                    write_diff(out, previous.as_ref(), &results);
+                    previous = Some(results);
+                    writeln!(out, "--- Watching {files} files for changes ---").unwrap();
+                }
+                Err(err) => {
+                    writeln!(out, "error: {err}").unwrap();
+                    writeln!(out, "--- Run failed, waiting for changes ---").unwrap();
+                }
+            }
+        }
+        out.flush().unwrap();
+        log::flush();
+        let Some(changed) = wait() else {
+            return Ok(());
+        };
+        log::info!("{changed:?} changed, running the puzzles again");
+        outdated |= changed == Change::Sources;
+    }
+}
+
+/// Rebuilds the binary with the profile it was built with. Returns whether the build succeeded.
+fn rebuild() -> Result<bool, String> {
+    let mut cargo = Command::new("cargo");
+    let _ = cargo
+        .args(["build", "--bin", env!("CARGO_PKG_NAME")])
+        .current_dir(env!("CARGO_MANIFEST_DIR"));
+    if !cfg!(debug_assertions) {
+        let _ = cargo.arg("--release");
+    }
+    log::info!("rebuilding with {cargo:?}");
+    let status = cargo
+        .status()
+        .map_err(|err| format!("failed to run cargo: {err}"))?;
+
+    Ok(status.success())
+}
+
+fn run_child(exe: &Path, args: &[String]) -> Result<Results, String> {
+    // Only the results are read from the output. Diagnostics like logs and panic messages go
+    // straight to stderr, as if the puzzles were run without `--watch`.
+    let output = Command::new(exe)
+        .args(args)
+        .args(["--format", "csv"])
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|err| format!("failed to run the puzzles: {err}"))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    parse_results(&stdout)
+}
+
+/// Reads the results from the CSV output of the runner.
+fn parse_results(csv: &str) -> Result<Results, String> {
+    let mut records = parse_csv(csv).into_iter();
+    let header = records.next().ok_or("the puzzles printed no results")?;
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|column| column == name)
+            .ok_or_else(|| format!("missing column `{name}` in the results"))
+    };
+    let day = column("day")?;
+    let part = column("part")?;
+    let answer = column("answer")?;
+    let elapsed = column("elapsed_ns")?;
+    let error = column("error")?;
+    let example = column("example")?;
+    let timed_out = column("timed_out")?;
+    // Panics are shown like errors.
+    let panic = column("panic").ok();
+    let invalid = |record: &[String]| format!("invalid result: `{}`", record.join(","));
+
+    let mut results = Results::new();
+    for record in records {
+        let field = |idx: usize| record.get(idx).filter(|field| !field.is_empty()).cloned();
+        let key = (
+            field(day)
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| invalid(&record))?,
+            match field(part).as_deref() {
+                Some("a") => Part::A,
+                Some("b") => Part::B,
+                _ => return Err(invalid(&record)),
+            },
+            field(example).and_then(|example| example.parse().ok()),
+        );
+        let elapsed = field(elapsed)
+            .and_then(|elapsed| elapsed.parse().ok())
+            .ok_or_else(|| invalid(&record))?;
+        let _ = results.insert(
+            key,
+            Record {
+                answer: field(answer),
+                elapsed: Duration::from_nanos(elapsed),
+                error: field(error).or_else(|| panic.and_then(field)),
+                timed_out: field(timed_out).as_deref() == Some("true"),
+            },
+        );
+    }
+
+    Ok(results)
+}
+
+/// Splits CSV into records, unquoting fields.
+fn parse_csv(csv: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = csv.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                let _ = chars.next();
+                field.push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => record.push(std::mem::take(&mut field)),
+            '\n' if !quoted => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            '\r' if !quoted => {}
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    records
+}
+
+/// Writes the results of a run, along with how they differ from the `previous` run.
+fn write_diff(out: &mut impl Write, previous: Option<&Results>, results: &Results) {
+    for (&(day, part, example), record) in results {
+        let previous = previous.and_then(|previous| previous.get(&(day, part, example)));
+        match example {
+            Some(example) => writeln!(
+                out,
+                "=== Day {day} - {} (example {example}) ===",
+                part.name()
+            ),
+            None => writeln!(out, "=== Day {day} - {} ===", part.name()),
+        }
+        .unwrap();
+        if let Some(err) = &record.error {
+            writeln!(out, "error: {err}").unwrap();
+        }
+        if let Some(answer) = &record.answer {
+            write!(out, "Solution: `{answer}`").unwrap();
+            match previous.map(|previous| &previous.answer) {
+                Some(Some(old)) if old == answer => write!(out, " (unchanged)").unwrap(),
+                Some(Some(old)) => write!(out, " (was `{old}`)").unwrap(),
+                Some(None) => write!(out, " (was an error)").unwrap(),
+                None => {}
+            }
+            writeln!(out).unwrap();
+        }
+        if record.timed_out {
+            writeln!(out, "Solution: TIMEOUT").unwrap();
+        }
+        write!(out, "Time: {:.2?}", record.elapsed).unwrap();
+        if let Some(previous) = previous.filter(|previous| !previous.elapsed.is_zero()) {
+            let change =
+                (record.elapsed.as_secs_f64() / previous.elapsed.as_secs_f64() - 1.0) * 100.0;
+            write!(out, " (was {:.2?}, {change:+.1}%)", previous.elapsed).unwrap();
+        }
+        writeln!(out).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, fs::File, path::Path, time::Duration};
+
+    use super::{
+        Change, Record, Results, Watcher, parse_csv, parse_results, run_child, run_loop, write_diff,
+    };
+    use crate::registry::Part;
+
+    #[test]
+    fn poll() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-watch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let source = dir.join("day9.rs");
+        let input = dir.join("day_9_a_input.txt");
+        std::fs::write(&source, "").unwrap();
+        let mut watcher = Watcher::from_paths(vec![source.clone()], vec![input.clone()]);
+        assert_eq!(watcher.poll(), None);
+
+        std::fs::write(&input, "7,1\n").unwrap();
+        assert_eq!(watcher.poll(), Some(Change::Inputs));
+        assert_eq!(watcher.poll(), None);
+
+        let modified = File::options().write(true).open(&source).unwrap();
+        modified
+            .set_modified(std::time::SystemTime::now() + Duration::from_secs(60))
+            .unwrap();
+        assert_eq!(watcher.poll(), Some(Change::Sources));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            parse_csv("a,b\n1,\"x, \"\"y\"\"\n z\"\n"),
+            [vec!["a", "b"], vec!["1", "x, \"y\"\n z"]]
+        );
+
+        let results = parse_results(
+            "day,part,answer,elapsed_ns,error,check,example,timed_out\n9,b,24,1500,,,,\n11,b,2,10,,pass,2,\n",
+        )
+        .unwrap();
+        assert_eq!(
+            results[&(9, Part::B, None)],
+            Record {
+                answer: Some("24".to_string()),
+                elapsed: Duration::from_nanos(1500),
+                error: None,
+                timed_out: false,
+            }
+        );
+        assert!(results.contains_key(&(11, Part::B, Some(2))));
+        assert!(parse_results("").is_err());
+    }
+
+    #[test]
+    fn diff() {
+        let record = |answer: &str, micros| Record {
+            answer: Some(answer.to_string()),
+            elapsed: Duration::from_micros(micros),
+            error: None,
+            timed_out: false,
+        };
+        let previous = Results::from([
+            ((9, Part::A, None), record("50", 200)),
+            ((9, Part::B, None), record("23", 1000)),
+        ]);
+        let results = Results::from([
+            ((9, Part::A, None), record("50", 100)),
+            ((9, Part::B, None), record("24", 1500)),
+        ]);
+
+        let mut out = Vec::new();
+        write_diff(&mut out, Some(&previous), &results);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "=== Day 9 - First puzzle ===
+Solution: `50` (unchanged)
+Time: 100.00µs (was 200.00µs, -50.0%)
+=== Day 9 - Second puzzle ===
+Solution: `24` (was `23`)
+Time: 1.50ms (was 1.00ms, +50.0%)
+"
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn rebuild() {
+        // The script stands in for the binary, and is replaced by every build like cargo does.
+        let dir = std::env::temp_dir().join(format!("aoc2025-rebuild-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let script = dir.join("aoc2025.sh");
+        let build = |answer: Option<u32>| {
+            let results = answer.map_or(String::new(), |answer| {
+                format!(
+                    "day,part,answer,elapsed_ns,error,example,timed_out\\n1,a,{answer},100,,,\\n"
+                )
+            });
+            let staged = dir.join("staged.sh");
+            std::fs::write(&staged, format!("printf '{results}'\n")).unwrap();
+            std::fs::rename(&staged, &script).unwrap();
+        };
+        build(Some(3));
+
+        let mut changes = [Change::Sources; 3].into_iter();
+        let builds = Cell::new(0);
+        let mut out = Vec::new();
+        run_loop(
+            &mut out,
+            2,
+            || changes.next(),
+            || {
+                builds.set(builds.get() + 1);
+                match builds.get() {
+                    1 => build(Some(4)),
+                    2 => return Ok(false),
+                    _ => build(None),
+                }
+                Ok(true)
+            },
+            || run_child(Path::new("sh"), &[script.display().to_string()]),
+        )
+        .unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(builds.get(), 3);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "=== Day 1 - First puzzle ===
+Solution: `3`
+Time: 100.00ns
+--- Watching 2 files for changes ---
+=== Day 1 - First puzzle ===
+Solution: `4` (was `3`)
+Time: 100.00ns (was 100.00ns, +0.0%)
+--- Watching 2 files for changes ---
+--- Build failed, waiting for changes ---
+error: the puzzles printed no results
+--- Run failed, waiting for changes ---
+"
+        );
+    }
+}
@@ -0,0 +1,32 @@
+//! The puzzles of Advent of Code 2025.
+
+use crate::registry::{Day, day};
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+// New days are added here by `aoc2025 new-day`, which expects one `day!` per line.
+pub const DAYS: &[Day] = &[
+    day!(1, day1::Day1),
+    day!(2, day2::Day2),
+    day!(3, day3::Day3),
+    day!(4, day4::Day4),
+    day!(5, day5::Day5),
+    day!(6, day6::Day6),
+    day!(7, day7::Day7),
+    day!(8, day8::Day8::new()),
+    day!(9, day9::Day9),
+    day!(10, day10::Day10),
+    day!(11, day11::Day11::new()),
+    day!(12, day12::Day12),
+];
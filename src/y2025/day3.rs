@@ -0,0 +1,182 @@
+use crate::{
+    answer::Answer,
+    error::Error,
+    solution::{Example, Solution},
+};
+
+/// Day 3: picking the batteries that give the largest joltage.
+#[derive(Debug, Clone)]
+pub struct Day3;
+
+impl Solution for Day3 {
+    /// The battery banks, one per line.
+    type Input<'a> = Vec<&'a str>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "987654321111111\n811111111111119\n234234234234278\n818181911112111\n",
+        part_a: Some("357"),
+        part_b: Some("3121910778619"),
+        params: &[],
+    }];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        input
+            .lines()
+            .map(
+                |bank| match bank.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+                    Some((idx, c)) => {
+                        Err(Error::at(&bank[idx..], format!("unexpected battery '{c}'")))
+                    }
+                    None => Ok(bank),
+                },
+            )
+            .collect()
+    }
+
+    fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle_a(input)?.into())
+    }
+
+    fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle_b(input)?.into())
+    }
+}
+
+/// Checks that a bank has enough batteries to turn on `count` of them.
+fn check_bank(bank: &str, count: usize) -> Result<(), Error> {
+    if bank.len() < count {
+        return Err(Error::at(
+            bank,
+            format!(
+                "a bank needs at least {count} batteries, but this one has {}",
+                bank.len()
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// The joltage of the turned on batteries, whose digits are ASCII digits.
+fn joltage(batteries: &[u8]) -> usize {
+    batteries
+        .iter()
+        .fold(0, |joltage, &b| joltage * 10 + usize::from(b - b'0'))
+}
+
+fn solve_puzzle_a(banks: &[&str]) -> Result<usize, Error> {
+    banks.iter().copied().map(solve_bank).sum()
+}
+
+fn solve_bank(input: &str) -> Result<usize, Error> {
+    check_bank(input, 2)?;
+    let bytes = input.as_bytes();
+    let mut result = [0, 0];
+    for idx in 0..(bytes.len() - 1) {
+        if bytes[idx] > result[0] {
+            result[0] = bytes[idx];
+            result[1] = find_largest(&bytes[idx + 1..]).1;
+        }
+    }
+
+    Ok(joltage(&result))
+}
+
+fn find_largest(input: &[u8]) -> (usize, u8) {
+    let mut largest = 0;
+    let mut idx = 0;
+    for (n, &b) in input.iter().enumerate() {
+        if b > largest {
+            largest = b;
+            idx = n;
+        }
+        if b == 9 {
+            break;
+        }
+    }
+
+    (idx, largest)
+}
+
+fn solve_puzzle_b(banks: &[&str]) -> Result<usize, Error> {
+    banks.iter().copied().map(solve_bank_two).sum()
+}
+
+fn solve_bank_two(input: &str) -> Result<usize, Error> {
+    check_bank(input, 12)?;
+    let bytes = input.as_bytes();
+    let mut result = [0; 12];
+    let mut start = 0;
+    let mut end = bytes.len() - 11;
+    for res in &mut result {
+        let (pos, num) = find_largest(&bytes[start..end]);
+        *res = num;
+        start += pos + 1;
+        end += 1;
+    }
+
+    Ok(joltage(&result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day3, solve_bank, solve_bank_two};
+    use crate::{
+        registry::Part,
+        solution::{Solution, assert_example},
+    };
+
+    #[test]
+    fn example() {
+        assert_example(Day3, 0, Part::A);
+    }
+
+    #[test]
+    fn example_banks() {
+        let inputs = [
+            "987654321111111",
+            "811111111111119",
+            "234234234234278",
+            "818181911112111",
+        ];
+        let expected = [98, 89, 78, 92];
+        for (input, expected) in inputs.into_iter().zip(expected) {
+            assert_eq!(solve_bank(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn example2() {
+        assert_example(Day3, 0, Part::B);
+    }
+
+    #[test]
+    fn example_banks2() {
+        let inputs = [
+            "987654321111111",
+            "811111111111119",
+            "234234234234278",
+            "818181911112111",
+        ];
+        let expected = [987654321111, 811111111119, 434234234278, 888911112111];
+        for (input, expected) in inputs.into_iter().zip(expected) {
+            assert_eq!(solve_bank_two(input), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn short_banks() {
+        let input = "98\n7\n";
+        let banks = Day3.parse(input).unwrap();
+        let err = Day3.part_a(&banks).unwrap_err().locate(input);
+        assert_eq!(
+            err.to_string(),
+            "2:1: a bank needs at least 2 batteries, but this one has 1"
+        );
+        let err = Day3.part_b(&banks).unwrap_err().locate(input);
+        assert_eq!(
+            err.to_string(),
+            "1:1: a bank needs at least 12 batteries, but this one has 2"
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{
+    answer::Answer,
+    common::grid::Grid,
+    error::Error,
+    solution::{Example, Solution},
+};
+
+/// Day 4: finding rolls of paper the forklifts can reach.
+#[derive(Debug, Clone)]
+pub struct Day4;
+
+/// A field of the grid, which may hold a roll of paper.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum Tile {
+    #[default]
+    Empty = 0,
+    Paper = 1,
+}
+
+impl Solution for Day4 {
+    type Input<'a> = Grid<Tile>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.\n",
+        part_a: Some("13"),
+        part_b: Some("43"),
+        params: &[],
+    }];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        Grid::parse(input, |b| match b {
+            b'.' => Some(Tile::Empty),
+            b'@' => Some(Tile::Paper),
+            _ => None,
+        })
+    }
+
+    fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle_a(input).into())
+    }
+
+    fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle_b(input).into())
+    }
+}
+
+fn solve_puzzle_a(grid: &Grid<Tile>) -> usize {
+    // Nothing gets removed, but the grid still has to be mutable for `evaluate_grid`.
+    evaluate_grid(&mut grid.clone(), false)
+}
+
+fn solve_puzzle_b(grid: &Grid<Tile>) -> usize {
+    let mut removed = 0;
+    let mut grid = grid.clone();
+
+    loop {
+        let removed_this_pass = evaluate_grid(&mut grid, true);
+
+        removed += removed_this_pass;
+        if removed_this_pass == 0 {
+            break;
+        }
+    }
+
+    removed
+}
+
+fn evaluate_grid(grid: &mut Grid<Tile>, remove: bool) -> usize {
+    let mut accessible = 0;
+    for y in 0..grid.height() {
+        for x in 0..grid.width() {
+            if Tile::Empty == grid[(x, y)] {
+                continue;
+            }
+
+            let neighbors = grid
+                .neighbors((x, y))
+                .filter(|&position| grid[position] == Tile::Paper)
+                .count();
+            if neighbors < 4 {
+                accessible += 1;
+                if remove {
+                    grid[(x, y)] = Tile::Empty;
+                }
+            }
+        }
+    }
+
+    accessible
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day4;
+    use crate::{registry::Part, solution::assert_example};
+
+    #[test]
+    fn example() {
+        assert_example(Day4, 0, Part::A);
+    }
+
+    #[test]
+    fn example2() {
+        assert_example(Day4, 0, Part::B);
+    }
+}
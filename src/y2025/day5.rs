@@ -0,0 +1,122 @@
+use std::ops::RangeInclusive;
+
+use crate::{
+    answer::Answer,
+    common::range,
+    error::{self, Error},
+    solution::{Example, Solution},
+};
+
+/// Day 5: checking ingredient IDs against the fresh ranges.
+#[derive(Debug, Clone)]
+pub struct Day5;
+
+/// The fresh ingredient ID ranges, and the available ingredient IDs.
+#[derive(Debug)]
+pub struct Database {
+    pub fresh: Vec<RangeInclusive<usize>>,
+    pub available: Vec<usize>,
+}
+
+impl Solution for Day5 {
+    type Input<'a> = Database;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n",
+        part_a: Some("3"),
+        part_b: Some("14"),
+        params: &[],
+    }];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        let mut fresh = Vec::new();
+        let mut lines = input.lines();
+        while let Some(line) = lines.next()
+            && !line.is_empty()
+        {
+            fresh.push(range::parse(line)?);
+        }
+        let available = lines.map(error::number).collect::<Result<_, _>>()?;
+
+        Ok(Database { fresh, available })
+    }
+
+    fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle_a(input).into())
+    }
+
+    fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle_b(input).into())
+    }
+}
+
+fn solve_puzzle_a(database: &Database) -> usize {
+    let mut fresh = 0;
+    for &id in &database.available {
+        if check_id(id, &database.fresh) {
+            fresh += 1;
+        }
+    }
+
+    fresh
+}
+
+fn solve_puzzle_b(database: &Database) -> usize {
+    let mut ranges: Vec<_> = database.fresh.iter().cloned().map(Some).collect();
+    ranges.sort_by_key(|r| *r.as_ref().unwrap().start());
+    for idx in 0..(ranges.len() - 1) {
+        let first = ranges[idx].as_ref();
+        let second = ranges[idx + 1].as_ref();
+        let first_end = first.as_ref().unwrap().end();
+        let second_start = second.as_ref().unwrap().start();
+        if second_start <= first_end {
+            let first_start = first.as_ref().unwrap().start();
+            let second_end = second.as_ref().unwrap().end();
+            let end = if first_end > second_end {
+                first_end
+            } else {
+                second_end
+            };
+            let merged = *first_start..=*end;
+            ranges[idx] = None;
+            ranges[idx + 1] = Some(merged);
+        }
+    }
+    let mut id_count = 0;
+    for range in ranges.iter().filter(|&o| o.is_some()) {
+        id_count += range.as_ref().unwrap().end() - range.as_ref().unwrap().start() + 1;
+    }
+
+    id_count
+}
+
+fn check_id(id: usize, ranges: &[RangeInclusive<usize>]) -> bool {
+    ranges.iter().any(|r| r.contains(&id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day5, check_id};
+    use crate::{registry::Part, solution::assert_example};
+
+    #[test]
+    fn example() {
+        assert_example(Day5, 0, Part::A);
+    }
+
+    #[test]
+    fn check_ids() {
+        let ranges = [3..=5, 10..=14, 16..=20, 12..=18];
+        let inputs = [1, 5, 8, 11, 17, 32];
+        let expected = [false, true, false, true, true, false];
+
+        for (&input, expected) in inputs.iter().zip(expected) {
+            assert_eq!(check_id(input, &ranges), expected);
+        }
+    }
+
+    #[test]
+    fn example2() {
+        assert_example(Day5, 0, Part::B);
+    }
+}
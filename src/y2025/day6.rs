@@ -0,0 +1,277 @@
+use crate::{
+    answer::Answer,
+    error::{self, Error},
+    input::Normalize,
+    solution::{Example, Solution},
+};
+
+/// Day 6: solving the cephalopod math worksheet.
+#[derive(Debug, Clone)]
+pub struct Day6;
+
+/// A problem on the worksheet: an operation, and the cells of its numbers in each row.
+#[derive(Debug)]
+pub struct Problem<'a> {
+    pub operation: Operation,
+    /// The cells can be narrower than the problem if the spaces at the end of a line are missing.
+    pub cells: Vec<&'a str>,
+    pub width: usize,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Multiply,
+}
+
+impl Operation {
+    fn apply(self, numbers: impl Iterator<Item = usize>) -> usize {
+        match self {
+            Operation::Add => numbers.sum(),
+            Operation::Multiply => numbers.product(),
+        }
+    }
+}
+
+impl Solution for Day6 {
+    /// The problems on the worksheet, from left to right. The puzzles read their numbers
+    /// differently, so they are kept as the text of the cells.
+    type Input<'a> = Vec<Problem<'a>>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n",
+        part_a: Some("4277556"),
+        part_b: Some("3263827"),
+        params: &[],
+    }];
+
+    /// The numbers are aligned in columns, so the spaces at the end of a line are significant.
+    const NORMALIZE: Normalize = Normalize {
+        trailing_whitespace: false,
+        ..Normalize::ALL
+    };
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        let lines: Vec<_> = input.lines().collect();
+        let Some((&operations, rows)) = lines.split_last() else {
+            return Err(Error::new("the worksheet is empty"));
+        };
+        if rows.is_empty() {
+            return Err(Error::at(operations, "the worksheet has no numbers"));
+        }
+        for line in rows {
+            check_line(line, |c| c.is_ascii_digit())?;
+        }
+        check_line(operations, |c| c == '+' || c == '*')?;
+
+        // Shorter lines are read as if they were padded with spaces to the longest one.
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        let blank = |idx: usize| {
+            rows.iter()
+                .all(|row| row.as_bytes().get(idx).is_none_or(|&b| b == b' '))
+        };
+        let mut columns = Vec::new();
+        let mut start = None;
+        for idx in 0..=width {
+            match (start, idx < width && !blank(idx)) {
+                (None, true) => start = Some(idx),
+                (Some(first), false) => {
+                    columns.push(first..idx);
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+
+        let mut operators = operations
+            .char_indices()
+            .filter(|&(_, c)| c != ' ')
+            .peekable();
+        if let Some(&(idx, c)) = operators.peek()
+            && columns.first().is_none_or(|first| idx < first.start)
+        {
+            return Err(Error::at(
+                &operations[idx..],
+                format!("operator '{c}' is not below any numbers"),
+            ));
+        }
+        let mut problems = Vec::new();
+        for (n, range) in columns.iter().enumerate() {
+            let next = columns.get(n + 1).map_or(usize::MAX, |next| next.start);
+            let describe = format!(
+                "the problem in columns {} to {}",
+                range.start + 1,
+                range.end
+            );
+            let Some((_, operator)) = operators.next_if(|&(idx, _)| idx < next) else {
+                let idx = range.start.min(operations.len());
+                return Err(Error::at(
+                    &operations[idx..],
+                    format!("missing the operator of {describe}"),
+                ));
+            };
+            if let Some((idx, _)) = operators.next_if(|&(idx, _)| idx < next) {
+                return Err(Error::at(
+                    &operations[idx..],
+                    format!("more than one operator for {describe}"),
+                ));
+            }
+
+            let mut cells = Vec::new();
+            for row in rows {
+                let cell = &row[range.start.min(row.len())..range.end.min(row.len())];
+                let number = cell.trim();
+                if number.is_empty() {
+                    return Err(Error::at(cell, format!("missing a number of {describe}")));
+                }
+                if number.contains(' ') {
+                    return Err(Error::at(
+                        number,
+                        format!("expected one number in {describe}, found '{number}'"),
+                    ));
+                }
+                cells.push(cell);
+            }
+            problems.push(Problem {
+                operation: if operator == '+' {
+                    Operation::Add
+                } else {
+                    Operation::Multiply
+                },
+                cells,
+                width: range.len(),
+            });
+        }
+
+        Ok(problems)
+    }
+
+    fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle_a(input)?.into())
+    }
+
+    fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle_b(input)?.into())
+    }
+}
+
+/// Checks that a line of the worksheet only holds spaces and the characters it should.
+fn check_line(line: &str, expected: impl Fn(char) -> bool) -> Result<(), Error> {
+    match line.char_indices().find(|&(_, c)| c != ' ' && !expected(c)) {
+        Some((idx, c)) => Err(Error::at(
+            &line[idx..],
+            format!("unexpected character '{c}'"),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn solve_puzzle_a(problems: &[Problem]) -> Result<usize, Error> {
+    let mut total = 0;
+    for problem in problems {
+        let numbers = problem
+            .cells
+            .iter()
+            .map(|cell| error::number(cell.trim()))
+            .collect::<Result<Vec<usize>, _>>()?;
+        total += problem.operation.apply(numbers.into_iter());
+    }
+
+    Ok(total)
+}
+
+fn solve_puzzle_b(problems: &[Problem]) -> Result<usize, Error> {
+    let mut total = 0;
+    for problem in problems {
+        let mut numbers = Vec::new();
+        for idx in 0..problem.width {
+            let digits: String = problem
+                .cells
+                .iter()
+                .filter_map(|cell| cell.as_bytes().get(idx))
+                .filter(|b| b.is_ascii_digit())
+                .map(|&b| char::from(b))
+                .collect();
+            let number = digits
+                .parse::<usize>()
+                .map_err(|_| Error::new(format!("invalid number '{digits}'")))?;
+            numbers.push(number);
+        }
+        total += problem.operation.apply(numbers.into_iter());
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day6;
+    use crate::{
+        registry::Part,
+        solution::{Solution, assert_example},
+    };
+
+    fn solve(input: &str, part: Part) -> Result<String, String> {
+        let problems = Day6
+            .parse(input)
+            .map_err(|err| err.locate(input).to_string())?;
+        let answer = match part {
+            Part::A => Day6.part_a(&problems),
+            Part::B => Day6.part_b(&problems),
+        };
+        answer
+            .map(|answer| answer.to_string())
+            .map_err(|err| err.locate(input).to_string())
+    }
+
+    #[test]
+    fn example() {
+        assert_example(Day6, 0, Part::A);
+    }
+
+    #[test]
+    fn example2() {
+        assert_example(Day6, 0, Part::B);
+    }
+
+    #[test]
+    fn ragged_lines() {
+        // Without the spaces at the end of the lines, or with the first line the longest.
+        let stripped = "123 328  51 64\n 45 64  387 23\n  6 98  215 314\n*   +   *   +\n";
+        assert_eq!(solve(stripped, Part::A), Ok("4277556".into()));
+        assert_eq!(solve(stripped, Part::B), Ok("3263827".into()));
+
+        let first_longest = "123 328  51 644\n 45 64  387 23\n  6 98  215 31\n*   +   *   +\n";
+        assert_eq!(solve(first_longest, Part::A), Ok("4277853".into()));
+        assert_eq!(solve(first_longest, Part::B), Ok("3263827".into()));
+    }
+
+    #[test]
+    fn operators() {
+        assert_eq!(
+            solve("1 2\n+\n", Part::A),
+            Err("2:2: missing the operator of the problem in columns 3 to 3".into())
+        );
+        assert_eq!(
+            solve("1 2\n+ +*\n", Part::B),
+            Err("2:4: more than one operator for the problem in columns 3 to 3".into())
+        );
+        assert_eq!(
+            solve(" 1\n+*\n", Part::A),
+            Err("2:1: operator '+' is not below any numbers".into())
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(
+            solve("1 2\n3\n+ *\n", Part::A),
+            Err("2:2: missing a number of the problem in columns 3 to 3".into())
+        );
+        assert_eq!(
+            solve("1 2\n333\n+\n", Part::A),
+            Err("1:1: expected one number in the problem in columns 1 to 3, found '1 2'".into())
+        );
+        assert_eq!(solve("12\n+\n", Part::B), Ok("3".into()));
+    }
+}
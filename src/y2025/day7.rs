@@ -0,0 +1,150 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    answer::Answer,
+    common::grid::Grid,
+    error::Error,
+    solution::{Example, Solution},
+};
+
+/// Day 7: following tachyon beams through a manifold.
+#[derive(Debug, Clone)]
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input<'a> = Manifold;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: ".......S.......\n...............\n.......^.......\n...............\n......^.^......\n...............\n.....^.^.^.....\n...............\n....^.^...^....\n...............\n...^.^...^.^...\n...............\n..^...^.....^..\n...............\n.^.^.^.^.^...^.\n...............\n",
+        part_a: Some("21"),
+        part_b: Some("40"),
+        params: &[],
+    }];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        input.try_into()
+    }
+
+    fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(input.count_splits().into())
+    }
+
+    fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(input.count_timelines().into())
+    }
+}
+
+/// A tachyon manifold, parsed from its diagram with [`Manifold::try_from`].
+#[derive(Debug)]
+pub struct Manifold {
+    start: (usize, usize),
+    splitters: HashSet<(usize, usize)>,
+    max_x: usize,
+    max_y: usize,
+}
+
+/// The state of a propagation through a [`Manifold`].
+#[derive(Debug, Default)]
+struct Beams {
+    beams: HashSet<(usize, usize)>,
+    memo: HashMap<(usize, usize), usize>,
+}
+
+impl Manifold {
+    /// Counts how often the beam entering at `S` gets split on its way through the manifold.
+    pub fn count_splits(&self) -> usize {
+        self.propagate(self.start, false, &mut Beams::default())
+    }
+
+    /// Counts the timelines a single tachyon particle entering at `S` ends up in.
+    pub fn count_timelines(&self) -> usize {
+        self.propagate(self.start, true, &mut Beams::default())
+    }
+
+    fn propagate(&self, from: (usize, usize), many_worlds: bool, state: &mut Beams) -> usize {
+        if let Some(result) = state.memo.get(&from) {
+            return *result;
+        }
+        if from.1 + 1 >= self.max_y {
+            if many_worlds {
+                return 1;
+            } else {
+                return 0;
+            }
+        }
+
+        let next = (from.0, from.1 + 1);
+        if !self.splitters.contains(&next) {
+            if state.beams.insert(next) || many_worlds {
+                let result = self.propagate(next, many_worlds, state);
+                let _ = state.memo.insert(next, result);
+                result
+            } else {
+                0
+            }
+        } else {
+            let mut sum = if many_worlds { 0 } else { 1 };
+            if let Some(x) = next.0.checked_sub(1) {
+                let left = (x, next.1);
+                if state.beams.insert(left) || many_worlds {
+                    let result = self.propagate(left, many_worlds, state);
+                    let _ = state.memo.insert(left, result);
+                    sum += result;
+                }
+            }
+            if next.0 + 1 < self.max_x {
+                let right = (next.0 + 1, next.1);
+                if state.beams.insert(right) || many_worlds {
+                    let result = self.propagate(right, many_worlds, state);
+                    let _ = state.memo.insert(right, result);
+                    sum += result;
+                }
+            }
+
+            sum
+        }
+    }
+}
+
+impl TryFrom<&str> for Manifold {
+    type Error = Error;
+
+    /// Parses a diagram, where `S` is the start of the beam and `^` are splitters.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let grid = Grid::parse(value, |b| matches!(b, b'.' | b'S' | b'^').then_some(b))?;
+        let mut splitters = HashSet::new();
+        let mut start = (0, 0);
+        for (position, tile) in grid.positions() {
+            match tile {
+                b'S' => start = position,
+                b'^' => {
+                    let _ = splitters.insert(position);
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(Self {
+            start,
+            splitters,
+            max_x: grid.width(),
+            max_y: grid.height(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day7;
+    use crate::{registry::Part, solution::assert_example};
+
+    #[test]
+    fn example() {
+        assert_example(Day7, 0, Part::A);
+    }
+
+    #[test]
+    fn example2() {
+        assert_example(Day7, 0, Part::B);
+    }
+}
@@ -0,0 +1,292 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{
+    answer::Answer,
+    error::{self, Error},
+    log,
+    solution::{Example, Solution},
+};
+
+/// Day 8: connecting junction boxes into circuits.
+#[derive(Debug, Clone)]
+pub struct Day8 {
+    /// How many of the closest pairs get connected in the first puzzle.
+    pub pairs: usize,
+    /// How many of the largest circuits are multiplied in the first puzzle.
+    pub circuits: usize,
+}
+
+impl Day8 {
+    pub const fn new() -> Self {
+        Self {
+            pairs: 1000,
+            circuits: 3,
+        }
+    }
+}
+
+impl Default for Day8 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Solution for Day8 {
+    type Input<'a> = Vec<JunctionBox>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "162,817,812\n57,618,57\n906,360,560\n592,479,940\n352,342,300\n466,668,158\n542,29,236\n431,825,988\n739,650,466\n52,470,668\n216,146,977\n819,987,18\n117,168,530\n805,96,715\n346,949,466\n970,615,88\n941,993,340\n862,61,35\n984,92,344\n425,690,689\n",
+        part_a: Some("40"),
+        part_b: Some("25272"),
+        params: &[("pairs", "10")],
+    }];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        input.lines().map(JunctionBox::try_from).collect()
+    }
+
+    fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle(input, self.pairs, self.circuits, false)?.into())
+    }
+
+    fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle(input, usize::MAX, usize::MAX, true)?.into())
+    }
+
+    fn set_param(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "pairs" => {
+                self.pairs = value
+                    .parse()
+                    .map_err(|_| format!("invalid number of pairs: `{value}`"))?;
+                Ok(())
+            }
+            "circuits" => {
+                self.circuits = value
+                    .parse()
+                    .map_err(|_| format!("invalid number of circuits: `{value}`"))?;
+                Ok(())
+            }
+            _ => Err(format!("unknown parameter `{name}`")),
+        }
+    }
+
+    fn params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("pairs", self.pairs.to_string()),
+            ("circuits", self.circuits.to_string()),
+        ]
+    }
+}
+
+/// Two junction boxes that could be connected, and the distance between them.
+#[derive(Debug, Copy, Clone)]
+pub struct Pair<'a> {
+    pub a: &'a JunctionBox,
+    pub b: &'a JunctionBox,
+    pub dist: f64,
+}
+
+/// A junction box, positioned in 3D space.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct JunctionBox {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+
+impl TryFrom<&str> for JunctionBox {
+    type Error = Error;
+
+    /// Parses a junction box from its `X,Y,Z` position.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (x, rest) = error::split_once(value, ',')?;
+        let (y, z) = error::split_once(rest, ',')?;
+
+        Ok(Self {
+            x: error::number(x)?,
+            y: error::number(y)?,
+            z: error::number(z)?,
+        })
+    }
+}
+
+/// Shows the position as it is written in the input.
+impl Display for JunctionBox {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+impl JunctionBox {
+    pub fn new(x: usize, y: usize, z: usize) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The straight-line distance between two junction boxes.
+    pub fn dist(&self, other: &Self) -> f64 {
+        let x = self.x.abs_diff(other.x).pow(2);
+        let y = self.y.abs_diff(other.y).pow(2);
+        let z = self.z.abs_diff(other.z).pow(2);
+
+        ((x + y + z) as f64).sqrt()
+    }
+}
+
+fn solve_puzzle(
+    junctions: &[JunctionBox],
+    num_pairs: usize,
+    num_circuits: usize,
+    part_two: bool,
+) -> Result<usize, Error> {
+    let num_junctions = junctions.len();
+    let mut pairs = build_pairs(junctions);
+    pairs.sort_unstable_by(|a, b| a.dist.total_cmp(&b.dist));
+    log::debug!(
+        "sorted {} pairs of {num_junctions} junction boxes by distance",
+        pairs.len()
+    );
+    let circuits = build_circuits(&pairs, num_pairs, num_junctions);
+
+    match (circuits, part_two) {
+        (Circuits::Connected(result), true) => Ok(result),
+        (Circuits::Separate(_), true) => Err(Error::new(
+            "the junction boxes never end up in a single circuit",
+        )),
+        (Circuits::Connected(_), false) => Err(Error::new(format!(
+            "all junction boxes end up in a single circuit within {num_pairs} pairs"
+        ))),
+        (Circuits::Separate(mut circuits), false) => {
+            circuits.sort_unstable_by_key(|circ| circ.len());
+
+            Ok(circuits
+                .iter()
+                .rev()
+                .take(num_circuits)
+                .map(|circ| circ.len())
+                .product())
+        }
+    }
+}
+
+/// Builds every pair of junction boxes, in no particular order.
+pub fn build_pairs(junctions: &[JunctionBox]) -> Vec<Pair<'_>> {
+    let mut pairs = Vec::new();
+    for (idx, junction) in junctions.iter().enumerate() {
+        for other in &junctions[idx + 1..] {
+            let pair = Pair {
+                a: junction,
+                b: other,
+                dist: junction.dist(other),
+            };
+            pairs.push(pair);
+        }
+    }
+
+    pairs
+}
+
+/// Connects the first `num_pairs` of `pairs`, which should be sorted by distance.
+///
+/// Stops early once all `num_junctions` junction boxes are part of the same circuit.
+pub fn build_circuits(pairs: &[Pair], num_pairs: usize, num_junctions: usize) -> Circuits {
+    let mut circuits: Vec<HashSet<JunctionBox>> = Vec::new();
+    let mut added_junctions = HashSet::new();
+    // The circuits that were not merged into others.
+    let mut separate = 0;
+    for (idx, (a, b)) in pairs.iter().take(num_pairs).map(|p| (p.a, p.b)).enumerate() {
+        log::trace!("connecting {a} and {b}, with {separate} separate circuits so far");
+        let mut connected = circuits
+            .iter_mut()
+            .filter(|circ| circ.contains(a) || circ.contains(b));
+        if let Some(circ) = connected.next() {
+            let _ = circ.insert(*a);
+            let _ = circ.insert(*b);
+            let _ = added_junctions.insert(*a);
+            let _ = added_junctions.insert(*b);
+            for other in connected {
+                circ.extend(other.drain());
+                separate -= 1;
+            }
+            if added_junctions.len() == num_junctions && separate == 1 {
+                log::debug!("all junction boxes are connected after {} pairs", idx + 1);
+                return Circuits::Connected(a.x * b.x);
+            }
+        } else {
+            let mut new_circ = HashSet::new();
+            let _ = new_circ.insert(*a);
+            let _ = new_circ.insert(*b);
+            let _ = added_junctions.insert(*a);
+            let _ = added_junctions.insert(*b);
+            circuits.push(new_circ);
+            separate += 1;
+        }
+    }
+    log::debug!("{separate} separate circuits are left");
+
+    Circuits::Separate(circuits)
+}
+
+/// The result of [`build_circuits`].
+#[derive(Debug)]
+pub enum Circuits {
+    /// Not all junction boxes were connected. Merged circuits are left behind empty.
+    Separate(Vec<HashSet<JunctionBox>>),
+    /// All junction boxes ended up in a single circuit. Holds the product of the X coordinates
+    /// of the last two junction boxes that were connected.
+    Connected(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Circuits, Day8, JunctionBox, build_circuits, build_pairs};
+    use crate::{
+        registry::Part,
+        solution::{Solution, assert_example},
+    };
+
+    #[test]
+    fn example() {
+        assert_example(Day8::new(), 0, Part::A);
+    }
+
+    #[test]
+    fn example2() {
+        assert_example(Day8::new(), 0, Part::B);
+    }
+
+    #[test]
+    fn single_circuit() {
+        let boxes = Day8::new().parse(Day8::EXAMPLES[0].input).unwrap();
+        let mut day = Day8::new();
+        day.pairs = 1000;
+        assert_eq!(
+            day.part_a(&boxes).unwrap_err().message,
+            "all junction boxes end up in a single circuit within 1000 pairs"
+        );
+
+        let boxes = Day8::new().parse("1,2,3\n").unwrap();
+        assert_eq!(
+            day.part_b(&boxes).unwrap_err().message,
+            "the junction boxes never end up in a single circuit"
+        );
+    }
+
+    #[test]
+    fn connected_once_merged() {
+        // Every box is in a circuit after the third pair, but only the fourth one joins them.
+        let boxes = [
+            JunctionBox::new(0, 0, 0),
+            JunctionBox::new(1, 0, 0),
+            JunctionBox::new(10, 0, 0),
+            JunctionBox::new(12, 0, 0),
+            JunctionBox::new(15, 0, 0),
+        ];
+        let mut pairs = build_pairs(&boxes);
+        pairs.sort_unstable_by(|a, b| a.dist.total_cmp(&b.dist));
+        assert!(matches!(
+            build_circuits(&pairs, usize::MAX, boxes.len()),
+            Circuits::Connected(10)
+        ));
+    }
+}
@@ -0,0 +1,182 @@
+use std::fmt::Display;
+
+use crate::{
+    answer::Answer,
+    error::{self, Error},
+    log,
+    solution::{Example, Solution},
+};
+
+/// Day 9: finding the largest rectangle between red tiles.
+#[derive(Debug, Clone)]
+pub struct Day9;
+
+impl Solution for Day9 {
+    /// The red tiles, in order.
+    type Input<'a> = Vec<Point>;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3\n",
+        part_a: Some("50"),
+        part_b: Some("24"),
+        params: &[],
+    }];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, Error> {
+        let points: Vec<_> = input
+            .lines()
+            .map(Point::try_from)
+            .collect::<Result<_, _>>()?;
+        // A rectangle needs two red tiles in its corners.
+        if points.len() < 2 {
+            return Err(Error::new(format!(
+                "expected at least 2 red tiles, found {}",
+                points.len()
+            )));
+        }
+
+        Ok(points)
+    }
+
+    fn part_a(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle_a(input).into())
+    }
+
+    fn part_b(&self, input: &Self::Input<'_>) -> Result<Answer, Error> {
+        Ok(solve_puzzle_b(input).into())
+    }
+}
+
+/// The position of a red tile.
+#[derive(Debug, Copy, Clone)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl TryFrom<&str> for Point {
+    type Error = Error;
+
+    /// Parses a point from its `X,Y` position.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (x, y) = error::split_once(value, ',')?;
+
+        Ok(Self {
+            x: error::number(x)?,
+            y: error::number(y)?,
+        })
+    }
+}
+
+/// Shows the position as it is written in the input.
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+fn solve_puzzle_a(points: &[Point]) -> usize {
+    let mut pairs = Vec::new();
+    for (idx, point) in points.iter().enumerate() {
+        for other in &points[idx + 1..] {
+            pairs.push((point, other));
+        }
+    }
+
+    log::debug!("comparing {} rectangles", pairs.len());
+    pairs
+        .iter()
+        .map(|(a, b)| (a.x.abs_diff(b.x) + 1) * (a.y.abs_diff(b.y) + 1))
+        .max()
+        .unwrap_or(0)
+}
+
+fn solve_puzzle_b(points: &[Point]) -> usize {
+    let mut pairs = Vec::new();
+    let mut vertical_edges = Vec::new();
+    let mut horizontal_edges = Vec::new();
+    for (idx, point) in points.iter().enumerate() {
+        let next = points[(idx + 1) % points.len()];
+        if point.x == next.x {
+            vertical_edges.push((point, next));
+        } else {
+            horizontal_edges.push((point, next));
+        }
+        for other in &points[idx + 1..] {
+            pairs.push((point, other));
+        }
+    }
+    pairs.sort_by(|a, b| {
+        let area_a = (a.0.x.abs_diff(a.1.x) + 1) * (a.0.y.abs_diff(a.1.y) + 1);
+        let area_b = (b.0.x.abs_diff(b.1.x) + 1) * (b.0.y.abs_diff(b.1.y) + 1);
+        area_b.cmp(&area_a)
+    });
+    log::debug!(
+        "checking {} rectangles against {} vertical and {} horizontal edges, largest first",
+        pairs.len(),
+        vertical_edges.len(),
+        horizontal_edges.len()
+    );
+    let mut max_area = 0;
+    for (idx, (a, b)) in pairs.into_iter().enumerate() {
+        let rect_top = a.y.min(b.y);
+        let rect_bottom = a.y.max(b.y);
+        let rect_left = a.x.min(b.x);
+        let rect_right = a.x.max(b.x);
+        if vertical_edges
+            .iter()
+            .filter(|&e| e.0.y.min(e.1.y) < rect_bottom && e.0.y.max(e.1.y) > rect_top)
+            .any(|e| {
+                !((rect_left <= e.0.x && rect_right <= e.0.x)
+                    || (rect_left >= e.0.x && rect_right >= e.0.x))
+            })
+        {
+            continue;
+        }
+        if horizontal_edges
+            .iter()
+            .filter(|&e| e.0.x.min(e.1.x) < rect_right && e.0.x.max(e.1.x) > rect_left)
+            .any(|e| {
+                !((rect_top <= e.0.y && rect_bottom <= e.0.y)
+                    || (rect_top >= e.0.y && rect_bottom >= e.0.y))
+            })
+        {
+            continue;
+        }
+        max_area = (a.x.abs_diff(b.x) + 1) * (a.y.abs_diff(b.y) + 1);
+        log::debug!(
+            "rectangle {} between {a} and {b} is inside the loop",
+            idx + 1
+        );
+        break;
+    }
+
+    max_area
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day9;
+    use crate::{
+        registry::Part,
+        solution::{Solution, assert_example},
+    };
+
+    #[test]
+    fn example() {
+        assert_example(Day9, 0, Part::A);
+    }
+
+    #[test]
+    fn example2() {
+        assert_example(Day9, 0, Part::B);
+    }
+
+    #[test]
+    fn too_few_tiles() {
+        for input in ["", "7,1\n"] {
+            let err = Day9.parse(input).unwrap_err();
+            assert!(err.message.starts_with("expected at least 2 red tiles"));
+        }
+    }
+}